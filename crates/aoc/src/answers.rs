@@ -0,0 +1,97 @@
+use std::{fmt, path::PathBuf};
+
+use common::{Result, input::InputSource};
+use serde::Deserialize;
+
+use crate::config::parse_toml;
+
+/// Registry of known correct answers, read from `answers.toml`
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    entries: Vec<Answer>,
+}
+
+/// The correct answer of one part of a day for one input file
+#[derive(Debug, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub expected: Expected,
+}
+
+/// Answers are written as TOML integers or strings, both compare by their text
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Number(number) => write!(f, "{number}"),
+            Expected::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(source: &InputSource) -> Result<Self> {
+        Answers::parse(&source.read()?).map_err(|err| err.with_file(source.to_string()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        parse_toml(text)
+    }
+
+    pub fn entries(&self) -> &[Answer] {
+        &self.entries
+    }
+
+    /// Every registered answer for the given day and part
+    pub fn for_part(&self, day: u8, part: u8) -> impl Iterator<Item = &Answer> {
+        self.entries
+            .iter()
+            .filter(move |answer| answer.day == day && answer.part == part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_numbers_and_text() {
+        let answers = Answers::parse(
+            r#"
+[[answer]]
+day = 1
+part = 1
+input = "puzzles/day01/input.txt"
+expected = 11
+
+[[answer]]
+day = 1
+part = 2
+input = "puzzles/day01/input.txt"
+expected = "31"
+"#,
+        )
+        .unwrap();
+
+        let expected: Vec<_> = answers.entries().iter().map(|answer| answer.expected.to_string()).collect();
+        assert_eq!(expected, ["11", "31"]);
+        assert_eq!(answers.for_part(1, 2).count(), 1);
+        assert_eq!(answers.for_part(2, 1).count(), 0);
+    }
+
+    #[test]
+    fn parse_error_points_at_value() {
+        let err = Answers::parse("[[answer]]\nday = \"one\"\n").unwrap_err();
+
+        assert_eq!(err.location().map(|location| location.line), Some(2));
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    fmt, fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use common::{Error, ParseMode, Result, input::InputSource};
+use serde::{Deserialize, Serialize};
+
+use crate::{config::parse_toml, days::Day};
+
+/// Timings of every benchmarked phase, written to and read from a results file
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Results {
+    #[serde(default, rename = "measurement")]
+    pub measurements: Vec<Measurement>,
+}
+
+/// Timings of one phase of a day, either parsing or solving one part
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    fn part(part: u8) -> Self {
+        if part == 1 { Phase::Part1 } else { Phase::Part2 }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part 1",
+            Phase::Part2 => "part 2",
+        })
+    }
+}
+
+/// Summary of the samples of one phase, in nanoseconds
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    /// Summarise at least one sample
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort_unstable();
+
+        // Nearest-rank percentile
+        let percentile = |percent: usize| {
+            let rank = (samples.len() * percent).div_ceil(100).max(1);
+            samples[rank - 1].as_nanos() as u64
+        };
+
+        Stats {
+            min_ns: samples[0].as_nanos() as u64,
+            median_ns: percentile(50),
+            p95_ns: percentile(95),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.1?}  median {:>10.1?}  p95 {:>10.1?}",
+            Duration::from_nanos(self.min_ns),
+            Duration::from_nanos(self.median_ns),
+            Duration::from_nanos(self.p95_ns)
+        )
+    }
+}
+
+/// Parse and solve `day` from `source` `iterations` times, timing parse and each part separately
+pub fn bench_day(day: &Day, source: &InputSource, iterations: usize) -> Result<Vec<Measurement>> {
+    let input = source.read()?;
+    let mut samples = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = day.parse(black_box(&input), ParseMode::Strict);
+        samples.push(start.elapsed());
+
+        parsed.map_err(|err| err.with_file(source.to_string()))?;
+    }
+
+    let mut measurements = vec![Measurement {
+        day: day.day,
+        phase: Phase::Parse,
+        stats: Stats::from_samples(samples),
+    }];
+
+    let parsed = day.load(source, ParseMode::Strict)?;
+
+    for part in 1..=day.parts {
+        let mut samples = Vec::with_capacity(iterations);
+
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let answer = parsed.solve(black_box(part));
+            samples.push(start.elapsed());
+
+            black_box(answer?);
+        }
+
+        measurements.push(Measurement {
+            day: day.day,
+            phase: Phase::part(part),
+            stats: Stats::from_samples(samples),
+        });
+    }
+
+    Ok(measurements)
+}
+
+impl Results {
+    pub fn load(path: &Path) -> Result<Self> {
+        let source = InputSource::File(path.to_path_buf());
+        let text = source.read()?;
+
+        parse_toml(&text).map_err(|err| err.with_file(source.to_string()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = toml::to_string(self).map_err(|err| Error::validation(err.to_string()))?;
+
+        fs::write(path, text).map_err(|error| Error::Io {
+            file: path.display().to_string(),
+            error,
+        })
+    }
+
+    /// Phases whose median is more than `threshold` percent slower than in `baseline`
+    pub fn regressions<'a>(&'a self, baseline: &'a Results, threshold: f64) -> Vec<Regression<'a>> {
+        self.measurements
+            .iter()
+            .filter_map(|current| {
+                let previous = baseline
+                    .measurements
+                    .iter()
+                    .find(|previous| previous.day == current.day && previous.phase == current.phase)?;
+
+                let change = (current.stats.median_ns as f64 / previous.stats.median_ns.max(1) as f64 - 1.0) * 100.0;
+
+                (change > threshold).then_some(Regression { current, previous, change })
+            })
+            .collect()
+    }
+}
+
+/// A phase that got slower than the baseline allows
+pub struct Regression<'a> {
+    pub current: &'a Measurement,
+    pub previous: &'a Measurement,
+    /// Change of the median in percent
+    pub change: f64,
+}
+
+impl fmt::Display for Regression<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {} {}: median {:.1?} against {:.1?} in the baseline (+{:.1}%)",
+            self.current.day,
+            self.current.phase,
+            Duration::from_nanos(self.current.stats.median_ns),
+            Duration::from_nanos(self.previous.stats.median_ns),
+            self.change
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(phase: Phase, median_ns: u64) -> Measurement {
+        Measurement {
+            day: 1,
+            phase,
+            stats: Stats { min_ns: median_ns, median_ns, p95_ns: median_ns },
+        }
+    }
+
+    #[test]
+    fn stats_use_nearest_rank() {
+        let samples = (1..=20).rev().map(Duration::from_nanos).collect();
+
+        assert_eq!(
+            Stats::from_samples(samples),
+            Stats { min_ns: 1, median_ns: 10, p95_ns: 19 }
+        );
+        assert_eq!(
+            Stats::from_samples(vec![Duration::from_nanos(7)]),
+            Stats { min_ns: 7, median_ns: 7, p95_ns: 7 }
+        );
+    }
+
+    #[test]
+    fn regressions_respect_threshold() {
+        let baseline = Results {
+            measurements: vec![measurement(Phase::Parse, 100), measurement(Phase::Part1, 100)],
+        };
+        let current = Results {
+            measurements: vec![
+                measurement(Phase::Parse, 109),
+                measurement(Phase::Part1, 120),
+                measurement(Phase::Part2, 500),
+            ],
+        };
+
+        let regressions = current.regressions(&baseline, 10.0);
+
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].current.phase, Phase::Part1);
+    }
+
+    #[test]
+    fn results_round_trip() {
+        let results = Results {
+            measurements: vec![measurement(Phase::Part2, 42)],
+        };
+
+        let text = toml::to_string(&results).unwrap();
+        let parsed: Results = toml::from_str(&text).unwrap();
+
+        assert_eq!(parsed.measurements[0].phase, Phase::Part2);
+        assert_eq!(parsed.measurements[0].stats, results.measurements[0].stats);
+    }
+}
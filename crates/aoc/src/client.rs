@@ -0,0 +1,228 @@
+use std::{
+    fs, io,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use common::{Error, Result};
+
+use crate::config::Config;
+
+const USER_AGENT: &str = "github.com/jensk-dev/aoc-2024 (aoc runner)";
+
+/// Talks to the Advent of Code website with the session from the config
+pub struct Client {
+    base_url: String,
+    config: Config,
+    agent: ureq::Agent,
+    limiter: RateLimiter,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Client {
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            config: config.clone(),
+            agent,
+            limiter: RateLimiter {
+                stamp: config.inputs_dir.join(".last-request"),
+                interval: config.min_interval(),
+            },
+        }
+    }
+
+    /// GET `path` below the base URL and return the body of a successful response
+    pub fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}{path}", self.base_url);
+        let session = self.config.session()?;
+        self.limiter.wait()?;
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={session}"))
+            .header("User-Agent", USER_AGENT)
+            .call();
+
+        read_body(&url, response)
+    }
+
+    /// POST `fields` as a form to `path` below the base URL and return the body of a successful response
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<String> {
+        let url = format!("{}{path}", self.base_url);
+        let session = self.config.session()?;
+        self.limiter.wait()?;
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={session}"))
+            .header("User-Agent", USER_AGENT)
+            .send_form(fields.iter().copied());
+
+        read_body(&url, response)
+    }
+}
+
+fn read_body(url: &str, response: Result<ureq::http::Response<ureq::Body>, ureq::Error>) -> Result<String> {
+    let request_failed = |error: ureq::Error| Error::Io {
+        file: url.to_string(),
+        error: io::Error::other(error),
+    };
+
+    let mut response = response.map_err(request_failed)?;
+    let status = response.status();
+    let body = response.body_mut().read_to_string().map_err(request_failed)?;
+
+    if !status.is_success() {
+        let reason = body.lines().next().unwrap_or_default().trim();
+        return Err(Error::validation(format!("{url} answered {status}: {reason}")));
+    }
+
+    Ok(body)
+}
+
+/// Keeps requests at least `interval` apart, across invocations, through a timestamp file
+pub struct RateLimiter {
+    stamp: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimiter {
+    /// Sleep until the interval since the previous request has passed, then record this request
+    pub fn wait(&self) -> Result<()> {
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+
+        if let Some(remaining) = remaining(last, SystemTime::now(), self.interval) {
+            thread::sleep(remaining);
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let write = |error| Error::Io {
+            file: self.stamp.display().to_string(),
+            error,
+        };
+
+        if let Some(parent) = self.stamp.parent() {
+            fs::create_dir_all(parent).map_err(write)?;
+        }
+        fs::write(&self.stamp, now.as_millis().to_string()).map_err(write)
+    }
+}
+
+/// How long to wait before the next request, if at all
+fn remaining(last: Option<SystemTime>, now: SystemTime, interval: Duration) -> Option<Duration> {
+    let elapsed = now.duration_since(last?).unwrap_or_default();
+
+    interval.checked_sub(elapsed).filter(|remaining| !remaining.is_zero())
+}
+
+#[cfg(test)]
+pub mod test_server {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// Stand-in for the website that answers each connection with the next canned response
+    pub struct TestServer {
+        pub url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl TestServer {
+        pub fn start(responses: Vec<(u16, &'static str)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let recorded = Arc::clone(&requests);
+
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut request = String::new();
+                    let mut content_length = 0;
+
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some((name, value)) = line.split_once(':')
+                            && name.eq_ignore_ascii_case("content-length")
+                        {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                        request.push_str(&line);
+
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                    }
+
+                    let mut body_bytes = vec![0; content_length];
+                    reader.read_exact(&mut body_bytes).unwrap();
+                    request.push_str(&String::from_utf8_lossy(&body_bytes));
+                    recorded.lock().unwrap().push(request);
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+
+            TestServer { url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remaining_waits_out_the_interval() {
+        let now = UNIX_EPOCH + Duration::from_secs(100);
+        let interval = Duration::from_secs(5);
+
+        assert_eq!(remaining(None, now, interval), None);
+        assert_eq!(
+            remaining(Some(now - Duration::from_secs(2)), now, interval),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(remaining(Some(now - Duration::from_secs(5)), now, interval), None);
+    }
+
+    #[test]
+    fn rate_limiter_records_requests() {
+        let dir = tempfile::tempdir().unwrap();
+        let limiter = RateLimiter {
+            stamp: dir.path().join("nested/.last-request"),
+            interval: Duration::from_millis(200),
+        };
+
+        limiter.wait().unwrap();
+        let start = SystemTime::now();
+        limiter.wait().unwrap();
+
+        assert!(start.elapsed().unwrap() >= Duration::from_millis(150));
+    }
+}
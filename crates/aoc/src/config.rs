@@ -0,0 +1,97 @@
+use std::{env, io, path::PathBuf, time::Duration};
+
+use common::{Error, Location, Result, input::InputSource};
+use serde::{Deserialize, de::DeserializeOwned};
+
+/// Environment variable holding the session token, takes precedence over the config file
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Settings for talking to the Advent of Code website, read from `aoc.toml`
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    /// Website the inputs are downloaded from and answers are submitted to
+    pub base_url: String,
+    /// Directory holding one `dayNN/input.txt` per day
+    pub inputs_dir: PathBuf,
+    /// Minimum number of seconds between two requests to the website
+    pub min_interval_secs: u64,
+    /// File every submitted answer is recorded in
+    pub history: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: "https://adventofcode.com".to_string(),
+            inputs_dir: PathBuf::from("puzzles"),
+            min_interval_secs: 5,
+            history: PathBuf::from("submissions.toml"),
+        }
+    }
+}
+
+impl Config {
+    /// Read `path` if it exists, falling back to the defaults, and apply the environment
+    pub fn load(path: PathBuf) -> Result<Self> {
+        let source = InputSource::File(path);
+
+        let mut config = match source.read() {
+            Ok(text) => parse_toml(&text).map_err(|err| err.with_file(source.to_string()))?,
+            Err(Error::Io { error, .. }) if error.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(err) => return Err(err),
+        };
+
+        if let Ok(session) = env::var(SESSION_VAR)
+            && !session.trim().is_empty()
+        {
+            config.session = Some(session);
+        }
+
+        Ok(config)
+    }
+
+    pub fn session(&self) -> Result<&str> {
+        self.session.as_deref().map(str::trim).ok_or_else(|| {
+            Error::validation(format!(
+                "No session token, set {SESSION_VAR} or `session` in the config file"
+            ))
+        })
+    }
+
+    pub fn min_interval(&self) -> Duration {
+        Duration::from_secs(self.min_interval_secs)
+    }
+}
+
+/// Deserialize a TOML document, pointing parse errors at the offending text
+pub fn parse_toml<T: DeserializeOwned>(text: &str) -> Result<T> {
+    toml::from_str(text).map_err(|err| match err.span() {
+        Some(span) => Error::parse(err.message(), Location::in_input(text, span)),
+        None => Error::validation(err.message()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_keys_use_defaults() {
+        let config: Config = parse_toml("base_url = \"http://127.0.0.1:8080\"\n").unwrap();
+
+        assert_eq!(config.base_url, "http://127.0.0.1:8080");
+        assert_eq!(config.inputs_dir, PathBuf::from("puzzles"));
+        assert!(config.session().is_err());
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let err = parse_toml::<Config>("sesion = \"abc\"\n").unwrap_err();
+
+        assert_eq!(err.location().map(|location| location.line), Some(1));
+    }
+}
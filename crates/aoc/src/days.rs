@@ -0,0 +1,118 @@
+use std::path::PathBuf;
+
+use common::{Example, ParseMode, Result, Solution, input::InputSource, solution::solve_part};
+
+type ParseFn = fn(&str, ParseMode) -> Result<Box<dyn Parsed>>;
+
+/// A day registered with the runner
+pub struct Day {
+    pub day: u8,
+    /// Number of parts that have been solved
+    pub parts: u8,
+    pub examples: &'static [Example],
+    parse: ParseFn,
+}
+
+impl Day {
+    /// Parse `input` once so that every part can be solved from it
+    pub fn parse(&self, input: &str, mode: ParseMode) -> Result<Box<dyn Parsed>> {
+        (self.parse)(input, mode)
+    }
+
+    /// Read and parse `source`, naming it in any error
+    pub fn load(&self, source: &InputSource, mode: ParseMode) -> Result<Box<dyn Parsed>> {
+        source
+            .read()
+            .and_then(|input| self.parse(&input, mode))
+            .map_err(|err| err.with_file(source.to_string()))
+    }
+
+    pub fn default_path(&self) -> PathBuf {
+        format!("./puzzles/day{:02}/input.txt", self.day).into()
+    }
+
+    pub fn default_input(&self) -> InputSource {
+        InputSource::File(self.default_path())
+    }
+}
+
+/// Input of a registered day after parsing
+pub trait Parsed {
+    fn solve(&self, part: u8) -> Result<String>;
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn solve(&self, part: u8) -> Result<String> {
+        solve_part::<S>(&self.0, part)
+    }
+}
+
+fn parse<S: Solution + 'static>(input: &str, mode: ParseMode) -> Result<Box<dyn Parsed>> {
+    Ok(Box::new(ParsedInput::<S>(S::parse(input, mode)?)))
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            day: <$solution as Solution>::DAY,
+            parts: <$solution as Solution>::PARTS,
+            examples: <$solution as Solution>::EXAMPLES,
+            parse: parse::<$solution>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(day01::Day01),
+    day!(day02::Day02),
+    day!(day03::Day03),
+    day!(day04::Day04),
+    day!(day05::Day05),
+    day!(day06::Day06),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|registered| registered.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_once() {
+        for (idx, day) in DAYS.iter().enumerate() {
+            assert!(
+                DAYS[idx + 1..].iter().all(|other| other.day != day.day),
+                "day {} is registered twice",
+                day.day
+            );
+        }
+    }
+
+    #[test]
+    fn find_registered_day() {
+        let day = find(4).unwrap();
+
+        assert_eq!(day.parts, 2);
+        assert_eq!(day.default_input(), InputSource::File("./puzzles/day04/input.txt".into()));
+    }
+
+    #[test]
+    fn find_unknown_day() {
+        assert!(find(25).is_none());
+    }
+
+    #[test]
+    fn parsed_input_solves_each_part() {
+        let parsed = find(2)
+            .unwrap()
+            .parse("7 6 4 2 1\n1 2 7 8 9\n1 3 2 4 5\n", ParseMode::Strict)
+            .unwrap();
+
+        assert_eq!(parsed.solve(1).unwrap(), "1");
+        assert_eq!(parsed.solve(2).unwrap(), "2");
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{Error, Result};
+
+use crate::client::Client;
+
+/// Where a fetched input ended up
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already cached and no request was made
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Cache location of the input of `day`, matching the layout of the day crates
+pub fn input_path(inputs_dir: &Path, day: u8) -> PathBuf {
+    inputs_dir.join(format!("day{day:02}")).join("input.txt")
+}
+
+/// Download the input of `day` unless it is already cached
+pub fn fetch(client: &Client, inputs_dir: &Path, day: u8) -> Result<Fetched> {
+    let path = input_path(inputs_dir, day);
+
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.get(&format!("/2024/day/{day}/input"))?;
+
+    // Write next to the target and rename so an interrupted download never looks cached
+    let partial = path.with_extension("txt.partial");
+    let write = |error| Error::Io {
+        file: path.display().to_string(),
+        error,
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(write)?;
+    }
+    fs::write(&partial, input).map_err(write)?;
+    fs::rename(&partial, &path).map_err(write)?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::test_server::TestServer, config::Config};
+
+    fn config(server: &TestServer, inputs_dir: &Path) -> Config {
+        Config {
+            session: Some("secret".to_string()),
+            base_url: server.url.clone(),
+            inputs_dir: inputs_dir.to_path_buf(),
+            min_interval_secs: 0,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn downloads_once_and_then_uses_the_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = TestServer::start(vec![(200, "3   4\n4   3\n")]);
+        let client = Client::new(&config(&server, dir.path()));
+        let path = input_path(dir.path(), 1);
+
+        assert_eq!(fetch(&client, dir.path(), 1).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fetch(&client, dir.path(), 1).unwrap(), Fetched::Cached(path.clone()));
+        assert_eq!(fs::read_to_string(path).unwrap(), "3   4\n4   3\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(requests[0].to_lowercase().contains("cookie: session=secret"));
+    }
+
+    #[test]
+    fn failed_download_is_not_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = TestServer::start(vec![(404, "Please don't repeatedly request this endpoint before it unlocks!")]);
+        let client = Client::new(&config(&server, dir.path()));
+
+        let err = fetch(&client, dir.path(), 25).unwrap_err();
+
+        assert!(err.to_string().contains("404"), "{err}");
+        assert!(!input_path(dir.path(), 25).exists());
+    }
+
+    #[test]
+    fn cached_input_needs_no_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = input_path(dir.path(), 2);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "1 2 3\n").unwrap();
+
+        let config = Config {
+            inputs_dir: dir.path().to_path_buf(),
+            ..Config::default()
+        };
+
+        assert_eq!(fetch(&Client::new(&config), dir.path(), 2).unwrap(), Fetched::Cached(path));
+    }
+}
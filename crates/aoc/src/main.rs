@@ -0,0 +1,495 @@
+use std::{
+    any::Any,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    process::ExitCode,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use clap::{Args, Parser, Subcommand};
+use common::{
+    ParseMode, Result,
+    cli::{Progress, Solver, solve_records},
+    input::{InputArgs, InputSource},
+    report::{Format, Record, render_json, render_table},
+};
+
+mod answers;
+mod bench;
+mod client;
+mod config;
+mod days;
+mod fetch;
+mod scaffold;
+mod submit;
+mod verify;
+
+use answers::Answers;
+use bench::Results;
+use client::Client;
+use config::Config;
+use days::{DAYS, Day};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 puzzle runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or more registered puzzles
+    Run(RunArgs),
+    /// Check every registered puzzle against the expected answers
+    Verify(VerifyArgs),
+    /// Time the parse and solve phases of registered puzzles
+    Bench(BenchArgs),
+    /// Download the input of a day unless it is already cached
+    Fetch(FetchArgs),
+    /// Solve a part and submit its answer to the website
+    Submit(SubmitArgs),
+    /// Create a crate for a new day and add it to the workspace
+    New(NewArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Part to solve, every solved part of the day when omitted
+    #[arg(requires = "day")]
+    part: Option<u8>,
+
+    /// Solve every registered puzzle
+    #[arg(long, conflicts_with = "input")]
+    all: bool,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// How the answers are printed
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Solve the puzzles on this many worker threads and print a summary once all are done
+    #[arg(long, short, value_name = "N", requires = "all")]
+    jobs: Option<NonZeroUsize>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Registry of expected answers
+    #[arg(long, value_name = "PATH", default_value = "answers.toml")]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark, every registered day when omitted
+    day: Option<u8>,
+
+    /// Number of timed runs of every phase
+    #[arg(long, short = 'n', default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// Write the results to this file
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Compare the results against a previously written results file
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+
+    /// Allowed slowdown of a median against the baseline, in percent
+    #[arg(long, default_value_t = 10.0, requires = "baseline")]
+    threshold: f64,
+}
+
+/// Where the website settings come from, flags override the config file
+#[derive(Args)]
+struct SiteArgs {
+    /// Config file with the session token and website settings
+    #[arg(long, value_name = "PATH", default_value = "aoc.toml")]
+    config: PathBuf,
+
+    /// Directory the inputs are cached in
+    #[arg(long, value_name = "DIR")]
+    inputs_dir: Option<PathBuf>,
+
+    /// Website to talk to
+    #[arg(long, value_name = "URL")]
+    base_url: Option<String>,
+}
+
+impl SiteArgs {
+    fn config(&self) -> Result<Config> {
+        let mut config = Config::load(self.config.clone())?;
+
+        if let Some(inputs_dir) = &self.inputs_dir {
+            config.inputs_dir = inputs_dir.clone();
+        }
+        if let Some(base_url) = &self.base_url {
+            config.base_url = base_url.clone();
+        }
+
+        Ok(config)
+    }
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day to download
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit
+    day: u8,
+
+    /// Part to submit
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Root of the workspace
+    #[arg(long, value_name = "DIR", default_value = ".")]
+    root: PathBuf,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(&args),
+        Command::Verify(args) => verify(&args),
+        Command::Bench(args) => bench(&args),
+        Command::Fetch(args) => fetch(&args),
+        Command::Submit(args) => submit(&args),
+        Command::New(args) => new(&args),
+    }
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    let selected: Vec<&Day> = match args.day {
+        Some(day) => days::find(day).into_iter().collect(),
+        None => DAYS.iter().collect(),
+    };
+
+    if selected.is_empty() || args.part.is_some_and(|part| part == 0 || part > selected[0].parts) {
+        eprintln!("No puzzle registered for the given day and part");
+        return ExitCode::FAILURE;
+    }
+
+    let records = match args.jobs {
+        Some(jobs) => solve_parallel(&selected, args, jobs.get()),
+        None => selected
+            .into_iter()
+            .flat_map(|day| solve_day(day, args, args.format == Format::Plain))
+            .collect(),
+    };
+
+    match args.format {
+        // Plain answers were printed while solving, except in parallel where they would interleave
+        Format::Plain if args.jobs.is_none() => {}
+        Format::Plain | Format::Table => println!("{}", render_table(&records)),
+        Format::Json => println!("{}", render_json(&records)),
+    }
+
+    if records.iter().any(|record| record.answer.is_err()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Solve `days` on `jobs` worker threads, a day that panics is recorded as failed
+fn solve_parallel(days: &[&Day], args: &RunArgs, jobs: usize) -> Vec<Record> {
+    let next = AtomicUsize::new(0);
+
+    let mut records: Vec<Record> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.min(days.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut records = Vec::new();
+
+                    while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        match panic::catch_unwind(AssertUnwindSafe(|| solve_day(day, args, false))) {
+                            Ok(solved) => records.extend(solved),
+                            Err(payload) => records.extend(panicked(day, args, payload.as_ref())),
+                        }
+                    }
+
+                    records
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("panics are caught per day"))
+            .collect()
+    });
+
+    records.sort_by_key(|record| (record.day, record.part));
+    records
+}
+
+/// Failed records for every selected part of a day whose solver panicked
+fn panicked(day: &Day, args: &RunArgs, payload: &(dyn Any + Send)) -> Vec<Record> {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause");
+
+    let parts = match args.part {
+        Some(part) => part..=part,
+        None => 1..=day.parts,
+    };
+
+    parts
+        .map(|part| Record {
+            day: day.day,
+            part,
+            input: args.input.name_or(day.default_path()),
+            answer: Err(format!("Solver panicked: {message}")),
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+        })
+        .collect()
+}
+
+/// Solve the selected parts of `day`, printing answers and errors as they come when `stream` is set
+fn solve_day(day: &Day, args: &RunArgs, stream: bool) -> Vec<Record> {
+    let parts = match args.part {
+        Some(part) => part..=part,
+        None => 1..=day.parts,
+    };
+
+    let solver = Solver {
+        day: day.day,
+        examples: day.examples,
+        parse: &|input, mode| day.parse(input, mode),
+        solve: &|parsed, part| parsed.solve(part),
+    };
+
+    solve_records(&solver, parts, &args.input, &day.default_path(), |progress| {
+        if !stream {
+            return;
+        }
+
+        match progress {
+            Progress::Reading(_) => {}
+            Progress::Failed(err) => eprintln!("Day {}: {}", day.day, err.render()),
+            Progress::Solved(part, Ok(answer)) => println!("Day {} part {part}: {answer}", day.day),
+            Progress::Solved(part, Err(err)) => eprintln!("Day {} part {part}: {err}", day.day),
+        }
+    })
+}
+
+fn verify(args: &VerifyArgs) -> ExitCode {
+    let answers = match Answers::load(&InputSource::File(args.answers.clone())) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err.render());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if verify::report(&verify::checks(&answers)) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn bench(args: &BenchArgs) -> ExitCode {
+    let selected: Vec<&Day> = match args.day {
+        Some(day) => days::find(day).into_iter().collect(),
+        None => DAYS.iter().collect(),
+    };
+
+    if selected.is_empty() {
+        eprintln!("No puzzle registered for the given day");
+        return ExitCode::FAILURE;
+    }
+
+    let baseline = match args.baseline.as_deref().map(Results::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("{}", err.render());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut results = Results::default();
+    let mut failed = false;
+
+    for day in selected {
+        match bench::bench_day(day, &day.default_input(), args.iterations as usize) {
+            Ok(measurements) => {
+                for measurement in &measurements {
+                    println!("Day {} {:<6}  {}", measurement.day, measurement.phase, measurement.stats);
+                }
+
+                results.measurements.extend(measurements);
+            }
+            Err(err) => {
+                eprintln!("Day {}: {}", day.day, err.render());
+                failed = true;
+            }
+        }
+    }
+
+    if let Some(output) = &args.output
+        && let Err(err) = results.save(output)
+    {
+        eprintln!("{}", err.render());
+        failed = true;
+    }
+
+    if let Some(baseline) = &baseline {
+        let regressions = results.regressions(baseline, args.threshold);
+
+        for regression in &regressions {
+            eprintln!("Regression: {regression}");
+        }
+
+        failed |= !regressions.is_empty();
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fetch(args: &FetchArgs) -> ExitCode {
+    let fetched = args.site.config().and_then(|config| {
+        fetch::fetch(&Client::new(&config), &config.inputs_dir, args.day)
+    });
+
+    match fetched {
+        Ok(fetch::Fetched::Cached(path)) => {
+            println!("Day {} input is already cached at {}", args.day, path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(fetch::Fetched::Downloaded(path)) => {
+            println!("Downloaded the day {} input to {}", args.day, path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err.render());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(args: &SubmitArgs) -> ExitCode {
+    let Some(day) = days::find(args.day).filter(|day| args.part <= day.parts) else {
+        eprintln!("No puzzle registered for the given day and part");
+        return ExitCode::FAILURE;
+    };
+
+    let submitted = args.site.config().and_then(|config| {
+        let source = InputSource::File(fetch::input_path(&config.inputs_dir, args.day));
+        let answer = day.load(&source, ParseMode::Strict)?.solve(args.part)?;
+        println!("Day {} part {}: {answer}", args.day, args.part);
+
+        let response = submit::submit(&Client::new(&config), &config.history, args.day, args.part, &answer)?;
+        let bounds = submit::History::load(&config.history)?.bounds(args.day, args.part);
+
+        Ok((response, bounds))
+    });
+
+    match submitted {
+        Ok((response, bounds)) => {
+            println!("{response}");
+
+            if response == submit::Response::Judged(submit::Verdict::Correct) {
+                return ExitCode::SUCCESS;
+            }
+
+            if bounds != submit::Bounds::default() {
+                println!("Based on earlier attempts {bounds}");
+            }
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("{}", err.render());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new(args: &NewArgs) -> ExitCode {
+    match scaffold::scaffold(&args.root, args.day) {
+        Ok(dir) => {
+            println!("Created {}", dir.display());
+            println!("Register day{:02}::Day{:02} in crates/aoc to solve it with `aoc run`", args.day, args.day);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err.render());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_run_is_sorted_by_day_and_part() {
+        let Cli { command: Command::Run(args) } =
+            Cli::parse_from(["aoc", "run", "--all", "--example", "--jobs", "4", "--format", "json"])
+        else {
+            panic!("expected the run command");
+        };
+
+        let days: Vec<&Day> = DAYS.iter().collect();
+        let records = solve_parallel(&days, &args, 4);
+        let order: Vec<_> = records.iter().map(|record| (record.day, record.part)).collect();
+
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(order, sorted);
+        assert_eq!(records.len(), DAYS.iter().map(|day| day.parts as usize).sum::<usize>());
+        assert!(records.iter().all(|record| record.answer.is_ok()));
+    }
+
+    #[test]
+    fn panicked_records_name_the_input() {
+        let Cli { command: Command::Run(args) } = Cli::parse_from(["aoc", "run", "1", "--example"]) else {
+            panic!("expected the run command");
+        };
+
+        let records = panicked(&DAYS[0], &args, &"boom");
+
+        assert_eq!(records.len(), DAYS[0].parts as usize);
+        assert!(records.iter().all(|record| record.input == "<example>"));
+        assert_eq!(records[0].answer, Err("Solver panicked: boom".to_string()));
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{Error, Location, Result, input::InputSource};
+use toml_edit::{Array, DocumentMut, Item};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+
+/// Create `puzzles/dayNN` below `root` from the templates and add it to the workspace manifest
+pub fn scaffold(root: &Path, day: u8) -> Result<PathBuf> {
+    let name = format!("day{day:02}");
+    let dir = root.join("puzzles").join(&name);
+
+    if dir.exists() {
+        return Err(Error::validation(format!("{} already exists", dir.display())));
+    }
+
+    // Update the manifest in memory first so a manifest we cannot edit leaves no half-made crate behind
+    let manifest_path = root.join("Cargo.toml");
+    let source = InputSource::File(manifest_path.clone());
+    let manifest = register(&source.read()?, &format!("puzzles/{name}"))
+        .map_err(|err| err.with_file(source.to_string()))?;
+
+    let files = [
+        ("Cargo.toml", render(CARGO_TOML, day)),
+        ("src/lib.rs", render(LIB_RS, day)),
+        ("src/main.rs", render(MAIN_RS, day)),
+        ("example.txt", String::new()),
+        ("input.txt", String::new()),
+    ];
+
+    for (file, contents) in files {
+        write(&dir.join(file), &contents)?;
+    }
+    write(&manifest_path, &manifest)?;
+
+    Ok(dir)
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{crate}}", &format!("day{day:02}"))
+        .replace("{{type}}", &format!("Day{day:02}"))
+        .replace("{{day}}", &day.to_string())
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    let write = |error| Error::Io {
+        file: path.display().to_string(),
+        error,
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(write)?;
+    }
+    fs::write(path, contents).map_err(write)
+}
+
+/// Add `member` to `members` and `default-members`, leaving the layout of every other entry alone
+pub fn register(manifest: &str, member: &str) -> Result<String> {
+    let mut document: DocumentMut = manifest.parse().map_err(|err: toml_edit::TomlError| match err.span() {
+        Some(span) => Error::parse(err.message(), Location::in_input(manifest, span)),
+        None => Error::validation(err.message()),
+    })?;
+
+    let workspace = document
+        .get_mut("workspace")
+        .and_then(Item::as_table_like_mut)
+        .ok_or_else(|| Error::validation("The manifest has no [workspace] table"))?;
+
+    let members = workspace
+        .get_mut("members")
+        .and_then(Item::as_array_mut)
+        .ok_or_else(|| Error::validation("The workspace has no members list"))?;
+    insert_member(members, member);
+
+    if let Some(default_members) = workspace.get_mut("default-members").and_then(Item::as_array_mut) {
+        insert_member(default_members, member);
+    }
+
+    Ok(document.to_string())
+}
+
+/// Insert after the last puzzle that sorts before `member`, laid out like its neighbour
+fn insert_member(array: &mut Array, member: &str) {
+    if array.iter().any(|value| value.as_str() == Some(member)) {
+        return;
+    }
+
+    let position = array
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| value.as_str().is_some_and(|path| path.starts_with("puzzles/") && path < member))
+        .last()
+        .map_or(0, |(idx, _)| idx + 1);
+    let decor = array
+        .get(position.min(array.len().saturating_sub(1)))
+        .map(|neighbour| neighbour.decor().clone());
+
+    array.insert(position, member);
+
+    if let (Some(decor), Some(value)) = (decor, array.get_mut(position)) {
+        *value.decor_mut() = decor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[workspace]
+resolver = "3"
+members = ["puzzles/day01", "puzzles/day02","puzzles/day06", "crates/aoc", "crates/common"]
+default-members = [
+    "puzzles/day01",
+    "puzzles/day06",
+    "crates/aoc",
+    "crates/common"
+]
+"#;
+
+    #[test]
+    fn register_keeps_layout() {
+        assert_eq!(
+            register(MANIFEST, "puzzles/day07").unwrap(),
+            r#"[workspace]
+resolver = "3"
+members = ["puzzles/day01", "puzzles/day02","puzzles/day06", "puzzles/day07", "crates/aoc", "crates/common"]
+default-members = [
+    "puzzles/day01",
+    "puzzles/day06",
+    "puzzles/day07",
+    "crates/aoc",
+    "crates/common"
+]
+"#
+        );
+    }
+
+    #[test]
+    fn register_keeps_days_sorted_and_is_idempotent() {
+        let manifest = register(MANIFEST, "puzzles/day03").unwrap();
+
+        assert!(manifest.contains(r#""puzzles/day02","puzzles/day03","puzzles/day06""#));
+        assert_eq!(register(&manifest, "puzzles/day03").unwrap(), manifest);
+    }
+
+    #[test]
+    fn scaffold_creates_crate() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("Cargo.toml"), MANIFEST).unwrap();
+
+        let dir = scaffold(root.path(), 7).unwrap();
+
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day07"));
+        assert!(lib.contains("const DAY: u8 = 7;"));
+        assert!(fs::read_to_string(dir.join("Cargo.toml")).unwrap().contains("name = \"day07\""));
+        assert!(fs::read_to_string(dir.join("src/main.rs")).unwrap().contains("\"./puzzles/day07/input.txt\""));
+        assert!(dir.join("input.txt").exists());
+        assert!(dir.join("example.txt").exists());
+        assert!(fs::read_to_string(root.path().join("Cargo.toml")).unwrap().contains("\"puzzles/day07\""));
+
+        assert!(scaffold(root.path(), 7).is_err());
+    }
+}
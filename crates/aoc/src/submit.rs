@@ -0,0 +1,334 @@
+use std::{
+    fmt, fs, io,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use common::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, config::parse_toml};
+
+/// How the website judged a submitted answer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint about the direction
+    Wrong,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        })
+    }
+}
+
+/// What the website answered to a submission
+#[derive(Debug, PartialEq, Eq)]
+pub enum Response {
+    Judged(Verdict),
+    /// Submitted too soon after a previous answer, nothing was judged
+    Wait(Duration),
+    /// The part was already solved or is not unlocked yet
+    WrongLevel,
+    Unknown(String),
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Response::Judged(verdict) => write!(f, "The answer is {verdict}"),
+            Response::Wait(wait) => write!(f, "Answered too recently, wait {}s before submitting again", wait.as_secs()),
+            Response::WrongLevel => write!(f, "This part is already solved or not unlocked yet"),
+            Response::Unknown(text) => write!(f, "Unrecognised response: {text}"),
+        }
+    }
+}
+
+/// Recognise the message in the page returned after posting an answer
+pub fn parse_response(html: &str) -> Response {
+    let text = strip_tags(html);
+    let lower = text.to_lowercase();
+
+    if lower.contains("that's the right answer") {
+        Response::Judged(Verdict::Correct)
+    } else if lower.contains("too high") {
+        Response::Judged(Verdict::TooHigh)
+    } else if lower.contains("too low") {
+        Response::Judged(Verdict::TooLow)
+    } else if lower.contains("not the right answer") {
+        Response::Judged(Verdict::Wrong)
+    } else if lower.contains("answer too recently") {
+        Response::Wait(parse_wait(&lower).unwrap_or(Duration::from_secs(60)))
+    } else if lower.contains("solving the right level") {
+        Response::WrongLevel
+    } else {
+        Response::Unknown(text.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let body = html.find("<article").map_or(html, |start| &html[start..]);
+    let mut text = String::with_capacity(body.len());
+    let mut in_tag = false;
+
+    for char in body.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+/// Parse "you have 1m 30s left to wait"
+fn parse_wait(text: &str) -> Option<Duration> {
+    let end = text.find(" left to wait")?;
+    let start = text[..end].rfind("you have ")? + "you have ".len();
+
+    text[start..end].split_whitespace().try_fold(Duration::ZERO, |total, amount| {
+        let (number, unit) = amount.split_at(amount.find(|char: char| !char.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        let seconds = match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+
+        Some(total + Duration::from_secs(seconds))
+    })
+}
+
+/// Every judged submission, kept so known-wrong answers are never sent again
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct History {
+    #[serde(default, rename = "attempt")]
+    attempts: Vec<Attempt>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch
+    pub submitted_at: u64,
+}
+
+/// What the history knows about the answer of a part
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Bounds {
+    /// The answer must be higher than this
+    pub above: Option<i64>,
+    /// The answer must be lower than this
+    pub below: Option<i64>,
+}
+
+impl fmt::Display for Bounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.above, self.below) {
+            (Some(above), Some(below)) => write!(f, "the answer must be higher than {above} and lower than {below}"),
+            (Some(above), None) => write!(f, "the answer must be higher than {above}"),
+            (None, Some(below)) => write!(f, "the answer must be lower than {below}"),
+            (None, None) => write!(f, "nothing is known about the answer yet"),
+        }
+    }
+}
+
+impl History {
+    /// Read the history, an absent file is an empty history
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => parse_toml(&text).map_err(|err| err.with_file(path.display().to_string())),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(error) => Err(Error::Io {
+                file: path.display().to_string(),
+                error,
+            }),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = toml::to_string(self).map_err(|err| Error::validation(err.to_string()))?;
+
+        fs::write(path, text).map_err(|error| Error::Io {
+            file: path.display().to_string(),
+            error,
+        })
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, verdict: Verdict) {
+        let submitted_at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+        self.attempts.push(Attempt {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at,
+        });
+    }
+
+    fn attempts(&self, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.part == part)
+    }
+
+    /// Tightest bounds implied by the too high and too low answers of a part
+    pub fn bounds(&self, day: u8, part: u8) -> Bounds {
+        let mut bounds = Bounds::default();
+
+        for attempt in self.attempts(day, part) {
+            let Ok(answer) = attempt.answer.parse::<i64>() else {
+                continue;
+            };
+
+            match attempt.verdict {
+                Verdict::TooLow => bounds.above = bounds.above.max(Some(answer)),
+                Verdict::TooHigh => bounds.below = Some(bounds.below.map_or(answer, |below| below.min(answer))),
+                Verdict::Correct | Verdict::Wrong => {}
+            }
+        }
+
+        bounds
+    }
+
+    /// Why `answer` should not be submitted, if the history already tells how it would be judged
+    pub fn refusal(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+        if let Some(correct) = self.attempts(day, part).find(|attempt| attempt.verdict == Verdict::Correct) {
+            return Some(format!("Day {day} part {part} was already solved with {}", correct.answer));
+        }
+
+        if let Some(attempt) = self.attempts(day, part).find(|attempt| attempt.answer == answer) {
+            return Some(format!("{answer} was already submitted and was {}", attempt.verdict));
+        }
+
+        let bounds = self.bounds(day, part);
+        let outside = answer.parse::<i64>().is_ok_and(|answer| {
+            bounds.above.is_some_and(|above| answer <= above) || bounds.below.is_some_and(|below| answer >= below)
+        });
+
+        outside.then(|| format!("{answer} cannot be right, {bounds}"))
+    }
+}
+
+/// Submit `answer` unless the history already rules it out, recording the verdict
+pub fn submit(client: &Client, history_path: &Path, day: u8, part: u8, answer: &str) -> Result<Response> {
+    let mut history = History::load(history_path)?;
+
+    if let Some(refusal) = history.refusal(day, part, answer) {
+        return Err(Error::validation(refusal));
+    }
+
+    let level = part.to_string();
+    let html = client.post_form(&format!("/2024/day/{day}/answer"), &[("level", &level), ("answer", answer)])?;
+    let response = parse_response(&html);
+
+    if let Response::Judged(verdict) = response {
+        history.record(day, part, answer, verdict);
+        history.save(history_path)?;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::test_server::TestServer, config::Config};
+
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  \
+        If you're stuck, make sure you're using the full input data. \
+        Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article></main>";
+
+    #[test]
+    fn recognises_responses() {
+        assert_eq!(parse_response(TOO_HIGH), Response::Judged(Verdict::TooHigh));
+        assert_eq!(
+            parse_response("<article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article>"),
+            Response::Judged(Verdict::Correct)
+        );
+        assert_eq!(
+            parse_response("<article><p>That's not the right answer; your answer is too low.</p></article>"),
+            Response::Judged(Verdict::TooLow)
+        );
+        assert_eq!(
+            parse_response(
+                "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>"
+            ),
+            Response::Judged(Verdict::Wrong)
+        );
+        assert_eq!(
+            parse_response(
+                "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  \
+                 You have 1m 32s left to wait.</p></article>"
+            ),
+            Response::Wait(Duration::from_secs(92))
+        );
+        assert_eq!(
+            parse_response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            Response::WrongLevel
+        );
+    }
+
+    #[test]
+    fn history_refuses_known_answers_and_reports_bounds() {
+        let mut history = History::default();
+        history.record(1, 1, "100", Verdict::TooHigh);
+        history.record(1, 1, "80", Verdict::TooHigh);
+        history.record(1, 1, "20", Verdict::TooLow);
+        history.record(1, 1, "abc", Verdict::Wrong);
+
+        assert_eq!(history.bounds(1, 1), Bounds { above: Some(20), below: Some(80) });
+        assert_eq!(history.refusal(1, 1, "abc").unwrap(), "abc was already submitted and was wrong");
+        assert_eq!(
+            history.refusal(1, 1, "90").unwrap(),
+            "90 cannot be right, the answer must be higher than 20 and lower than 80"
+        );
+        assert_eq!(history.refusal(1, 1, "50"), None);
+        assert_eq!(history.refusal(1, 2, "90"), None);
+
+        history.record(1, 1, "50", Verdict::Correct);
+        assert!(history.refusal(1, 1, "51").unwrap().contains("already solved with 50"));
+    }
+
+    #[test]
+    fn submission_is_recorded_and_not_repeated() {
+        let dir = tempfile::tempdir().unwrap();
+        let history = dir.path().join("submissions.toml");
+        let server = TestServer::start(vec![(200, TOO_HIGH)]);
+        let client = Client::new(&Config {
+            session: Some("secret".to_string()),
+            base_url: server.url.clone(),
+            inputs_dir: dir.path().to_path_buf(),
+            min_interval_secs: 0,
+            ..Config::default()
+        });
+
+        let response = submit(&client, &history, 1, 2, "123").unwrap();
+        assert_eq!(response, Response::Judged(Verdict::TooHigh));
+
+        let err = submit(&client, &history, 1, 2, "200").unwrap_err();
+        assert_eq!(err.to_string(), "200 cannot be right, the answer must be lower than 123");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("POST /2024/day/1/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=123"));
+        assert_eq!(History::load(&history).unwrap().bounds(1, 2).below, Some(123));
+    }
+}
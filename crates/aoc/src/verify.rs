@@ -0,0 +1,148 @@
+use common::{Error, ParseMode, Result, input::InputSource};
+
+use crate::{
+    answers::Answers,
+    days::{self, DAYS},
+};
+
+/// Result of comparing one solved part against the registry
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail,
+    Missing,
+}
+
+/// One day, part and input that was solved during verification
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub input: InputSource,
+    pub expected: Option<String>,
+    pub actual: Result<String>,
+}
+
+impl Check {
+    pub fn outcome(&self) -> Outcome {
+        match (&self.expected, &self.actual) {
+            (None, _) => Outcome::Missing,
+            (Some(expected), Ok(actual)) if expected == actual => Outcome::Pass,
+            (Some(_), _) => Outcome::Fail,
+        }
+    }
+
+    fn describe(&self) -> String {
+        let actual = match &self.actual {
+            Ok(actual) => actual.clone(),
+            Err(err) => format!("error: {err}"),
+        };
+
+        match &self.expected {
+            Some(expected) if self.outcome() == Outcome::Fail => {
+                format!("expected {expected}, got {actual}")
+            }
+            _ => actual,
+        }
+    }
+}
+
+/// Solve every registered answer, plus the default input of every solved part that has none
+pub fn checks(answers: &Answers) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for day in DAYS {
+        for part in 1..=day.parts {
+            if answers.for_part(day.day, part).next().is_none() {
+                let input = day.default_input();
+                let actual = solve(day.day, part, &input);
+
+                checks.push(Check { day: day.day, part, input, expected: None, actual });
+            }
+        }
+    }
+
+    for answer in answers.entries() {
+        let input = InputSource::File(answer.input.clone());
+        let actual = solve(answer.day, answer.part, &input);
+
+        checks.push(Check {
+            day: answer.day,
+            part: answer.part,
+            input,
+            expected: Some(answer.expected.to_string()),
+            actual,
+        });
+    }
+
+    checks.sort_by_key(|check| (check.day, check.part));
+    checks
+}
+
+fn solve(day: u8, part: u8, input: &InputSource) -> Result<String> {
+    let registered = days::find(day)
+        .filter(|registered| (1..=registered.parts).contains(&part))
+        .ok_or_else(|| Error::validation(format!("No puzzle registered for day {day} part {part}")))?;
+
+    registered.load(input, ParseMode::Strict)?.solve(part)
+}
+
+/// Print one line per check followed by a summary, returns whether every registered answer matched
+pub fn report(checks: &[Check]) -> bool {
+    let mut counts = [0; 3];
+
+    for check in checks {
+        let (label, idx) = match check.outcome() {
+            Outcome::Pass => ("PASS", 0),
+            Outcome::Fail => ("FAIL", 1),
+            Outcome::Missing => ("MISSING", 2),
+        };
+        counts[idx] += 1;
+
+        println!(
+            "{label:<7} day {} part {} ({}): {}",
+            check.day,
+            check.part,
+            check.input,
+            check.describe()
+        );
+    }
+
+    println!("{} passed, {} failed, {} missing", counts[0], counts[1], counts[2]);
+
+    counts[1] == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(expected: Option<&str>, actual: Result<String>) -> Check {
+        Check {
+            day: 1,
+            part: 1,
+            input: InputSource::Stdin,
+            expected: expected.map(str::to_string),
+            actual,
+        }
+    }
+
+    #[test]
+    fn outcome_compares_expected_and_actual() {
+        assert_eq!(check(Some("11"), Ok("11".into())).outcome(), Outcome::Pass);
+        assert_eq!(check(Some("11"), Ok("12".into())).outcome(), Outcome::Fail);
+        assert_eq!(check(Some("11"), Err(Error::unsolvable("no"))).outcome(), Outcome::Fail);
+        assert_eq!(check(None, Ok("11".into())).outcome(), Outcome::Missing);
+    }
+
+    #[test]
+    fn unregistered_answer_fails() {
+        let answers =
+            Answers::parse("[[answer]]\nday = 25\npart = 1\ninput = \"missing.txt\"\nexpected = 1\n").unwrap();
+
+        let check = checks(&answers).pop().unwrap();
+
+        assert_eq!(check.day, 25);
+        assert_eq!(check.outcome(), Outcome::Fail);
+        assert_eq!(check.describe(), "expected 1, got error: No puzzle registered for day 25 part 1");
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    ops::RangeInclusive,
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use clap::Parser;
+
+use crate::{
+    Example, ParseMode, Solution,
+    error::{Error, Result},
+    input::InputArgs,
+    report::{Format, Record, render_json, render_table},
+    solution::{examples_for, solve_part},
+};
+
+/// Arguments accepted by every day binary
+#[derive(Parser)]
+pub struct DayArgs {
+    /// Part to solve, every solved part of the day when omitted
+    #[arg(long, short, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    /// How the answers are printed
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+/// A day as the runners see it, parsing an input once and solving parts of it
+pub struct Solver<'a, I> {
+    pub day: u8,
+    pub examples: &'static [Example],
+    pub parse: &'a dyn Fn(&str, ParseMode) -> Result<I>,
+    pub solve: &'a dyn Fn(&I, u8) -> Result<String>,
+}
+
+/// What [`solve_records`] has done so far, for runners that print answers as they come
+pub enum Progress<'a> {
+    /// The named input is about to be read and parsed
+    Reading(&'a str),
+    /// No input could be selected, read or parsed for the parts that follow
+    Failed(&'a Error),
+    Solved(u8, &'a std::result::Result<String, String>),
+}
+
+/// Read, parse and solve `parts` of the input `args` select, falling back to `default_input`,
+/// with a record per part whether it was solved or not
+pub fn solve_records<I>(
+    solver: &Solver<'_, I>,
+    parts: RangeInclusive<u8>,
+    args: &InputArgs,
+    default_input: &Path,
+    mut progress: impl FnMut(Progress<'_>),
+) -> Vec<Record> {
+    let inputs = if args.example {
+        examples_for(solver.day, solver.examples, parts.clone()).map(|examples| {
+            examples
+                .into_iter()
+                .map(|(input, parts)| ("<example>".to_string(), Ok(input.to_string()), parts))
+                .collect()
+        })
+    } else {
+        let source = args.source_or(default_input);
+        Ok(vec![(source.to_string(), source.read(), parts.clone().collect())])
+    };
+
+    let inputs: Vec<(String, Result<String>, Vec<u8>)> = match inputs {
+        Ok(inputs) => inputs,
+        Err(err) => {
+            progress(Progress::Failed(&err));
+
+            return parts
+                .map(|part| Record {
+                    day: solver.day,
+                    part,
+                    input: args.name_or(default_input),
+                    answer: Err(err.to_string()),
+                    parse_time: Duration::ZERO,
+                    solve_time: Duration::ZERO,
+                })
+                .collect();
+        }
+    };
+
+    let mut records = Vec::new();
+
+    for (name, text, parts) in inputs {
+        progress(Progress::Reading(&name));
+
+        let start = Instant::now();
+        let input = text
+            .and_then(|text| (solver.parse)(&text, args.mode()))
+            .map_err(|err| err.with_file(name.clone()));
+        let parse_time = start.elapsed();
+
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                progress(Progress::Failed(&err));
+
+                records.extend(parts.into_iter().map(|part| Record {
+                    day: solver.day,
+                    part,
+                    input: name.clone(),
+                    answer: Err(err.to_string()),
+                    parse_time,
+                    solve_time: Duration::ZERO,
+                }));
+                continue;
+            }
+        };
+
+        for part in parts {
+            let start = Instant::now();
+            let answer = (solver.solve)(&input, part).map_err(|err| err.to_string());
+            let solve_time = start.elapsed();
+
+            progress(Progress::Solved(part, &answer));
+
+            records.push(Record {
+                day: solver.day,
+                part,
+                input: name.clone(),
+                answer,
+                parse_time,
+                solve_time,
+            });
+        }
+    }
+
+    records
+}
+
+/// Entry point shared by the day binaries
+pub fn run<S: Solution>(default_input: &str) -> ExitCode {
+    let args = DayArgs::parse();
+    let plain = args.format == Format::Plain;
+    let parts = match args.part {
+        Some(part) => part..=part,
+        None => 1..=S::PARTS,
+    };
+
+    if plain {
+        println!("\n----- Advent of Code 2024 - Day {} -----", S::DAY);
+    }
+
+    let solver = Solver {
+        day: S::DAY,
+        examples: S::EXAMPLES,
+        parse: &S::parse,
+        solve: &solve_part::<S>,
+    };
+
+    let records = solve_records(&solver, parts, &args.input, Path::new(default_input), |progress| {
+        if !plain {
+            return;
+        }
+
+        match progress {
+            Progress::Reading(name) => println!("Reading file: {name}"),
+            Progress::Failed(err) => eprintln!("{}", err.render()),
+            Progress::Solved(part, Ok(answer)) => println!("Part {part}: {answer}"),
+            Progress::Solved(part, Err(err)) => eprintln!("Part {part}: {err}"),
+        }
+    });
+
+    match args.format {
+        Format::Plain => println!("----- ------------------------- -----"),
+        Format::Table => println!("{}", render_table(&records)),
+        Format::Json => println!("{}", render_json(&records)),
+    }
+
+    if records.iter().any(|record| record.answer.is_err()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: &[Example] = &[Example {
+        part: 1,
+        input: "a\nb\n",
+        answer: "2",
+    }];
+
+    #[test]
+    fn records_every_selected_part() {
+        let solver = Solver {
+            day: 25,
+            examples: EXAMPLES,
+            parse: &|input: &str, _mode| Ok(input.lines().count()),
+            solve: &|lines: &usize, _part| Ok(lines.to_string()),
+        };
+        let args = InputArgs {
+            example: true,
+            ..InputArgs::default()
+        };
+        let mut solved = Vec::new();
+
+        let records = solve_records(&solver, 1..=1, &args, Path::new("input.txt"), |progress| {
+            if let Progress::Solved(part, answer) = progress {
+                solved.push((part, answer.clone()));
+            }
+        });
+
+        assert_eq!(solved, [(1, Ok("2".to_string()))]);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].input, "<example>");
+
+        let missing = solve_records(&solver, 1..=2, &args, Path::new("input.txt"), |_| {});
+        assert_eq!(missing.len(), 2);
+        assert!(missing.iter().all(|record| record.answer.is_err() && record.input == "<example>"));
+    }
+}
@@ -0,0 +1,239 @@
+use std::{error, fmt, io, ops::Range, result, str::FromStr};
+
+pub type Result<T, E = Error> = result::Result<T, E>;
+
+/// Where in the puzzle input a problem was found
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    /// Name of the input, filled in by whoever read it
+    pub file: Option<String>,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// The offending line
+    pub text: String,
+    /// Number of characters to underline, at least one
+    pub width: usize,
+}
+
+impl Location {
+    /// Location of the byte range `span` within `text`, which is line `line_idx` (0-based) of the input
+    pub fn new(line_idx: usize, text: &str, span: Range<usize>) -> Self {
+        let start = span.start.min(text.len());
+        let end = span.end.clamp(start, text.len());
+
+        Location {
+            file: None,
+            line: line_idx + 1,
+            column: text[..start].chars().count() + 1,
+            text: text.to_string(),
+            width: text[start..end].chars().count().max(1),
+        }
+    }
+
+    /// Location of the byte range `span` within the whole of `input`
+    pub fn in_input(input: &str, span: Range<usize>) -> Self {
+        let start = span.start.min(input.len());
+        let line_start = input[..start].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_idx = input[..line_start].matches('\n').count();
+        let text = input[line_start..].lines().next().unwrap_or("");
+
+        Location::new(line_idx, text, start - line_start..span.end.saturating_sub(line_start))
+    }
+
+    /// Location covering the whole of line `line_idx` (0-based)
+    pub fn line(line_idx: usize, text: &str) -> Self {
+        Location::new(line_idx, text, 0..text.len())
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+
+        write!(f, "{file}:{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input could not be read
+    Io { file: String, error: io::Error },
+    /// A line of the input does not follow the expected grammar
+    Parse { message: String, location: Location },
+    /// The input was read but breaks a constraint of the puzzle
+    Validation {
+        message: String,
+        location: Option<Location>,
+    },
+    /// No answer can be produced for the input
+    Unsolvable { message: String },
+}
+
+impl Error {
+    pub fn parse(message: impl Into<String>, location: Location) -> Self {
+        Error::Parse {
+            message: message.into(),
+            location,
+        }
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        Error::Validation {
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    pub fn validation_at(message: impl Into<String>, location: Location) -> Self {
+        Error::Validation {
+            message: message.into(),
+            location: Some(location),
+        }
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Error::Unsolvable {
+            message: message.into(),
+        }
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Error::Parse { location, .. } => Some(location),
+            Error::Validation { location, .. } => location.as_ref(),
+            Error::Io { .. } | Error::Unsolvable { .. } => None,
+        }
+    }
+
+    /// Attach the name of the input the error was found in
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        let location = match &mut self {
+            Error::Parse { location, .. } => Some(location),
+            Error::Validation { location, .. } => location.as_mut(),
+            Error::Io { .. } | Error::Unsolvable { .. } => None,
+        };
+
+        if let Some(location) = location {
+            location.file = Some(file.into());
+        }
+
+        self
+    }
+
+    /// Render the error together with a caret-underlined snippet of the offending line
+    pub fn render(&self) -> String {
+        let Some(location) = self.location() else {
+            return format!("error: {self}");
+        };
+
+        let message = match self {
+            Error::Parse { message, .. } | Error::Validation { message, .. } => message,
+            Error::Io { .. } | Error::Unsolvable { .. } => unreachable!("errors without a location"),
+        };
+
+        let gutter = " ".repeat(location.line.to_string().len());
+        let indent = " ".repeat(location.column - 1);
+        let carets = "^".repeat(location.width);
+
+        format!(
+            "error: {message}\n{gutter}--> {location}\n{gutter} |\n{} | {}\n{gutter} | {indent}{carets}",
+            location.line, location.text
+        )
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { file, error } if error.kind() == io::ErrorKind::NotFound => {
+                write!(f, "Input file not found: {file}")
+            }
+            Error::Io { file, error } => write!(f, "Failed to read {file}: {error}"),
+            Error::Parse { message, location } => write!(f, "{message} at {location}"),
+            Error::Validation {
+                message,
+                location: Some(location),
+            } => write!(f, "{message} at {location}"),
+            Error::Validation { message, .. } | Error::Unsolvable { message } => {
+                write!(f, "{message}")
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Parse `text[span]`, reporting a failure at that span of line `line_idx` (0-based)
+pub fn parse_at<T>(line_idx: usize, text: &str, span: Range<usize>) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text[span.clone()]
+        .parse()
+        .map_err(|err: T::Err| Error::parse(err.to_string(), Location::new(line_idx, text, span)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location_counts_characters() {
+        let location = Location::new(2, "ä 12x", 3..6);
+
+        assert_eq!(location.line, 3);
+        assert_eq!(location.column, 3);
+        assert_eq!(location.width, 3);
+    }
+
+    #[test]
+    fn location_in_input_finds_line() {
+        let location = Location::in_input("a = 1\nb = x\n", 10..11);
+
+        assert_eq!((location.line, location.column, location.width), (2, 5, 1));
+        assert_eq!(location.text, "b = x");
+    }
+
+    #[test]
+    fn parse_at_reports_span() {
+        let err = parse_at::<i32>(0, "12 99999999999", 3..14).unwrap_err();
+
+        assert_eq!(err.to_string(), "number too large to fit in target type at <input>:1:4");
+    }
+
+    #[test]
+    fn render_underlines_offending_text() {
+        let err = parse_at::<i32>(9, "3   4x", 4..6)
+            .unwrap_err()
+            .with_file("input.txt");
+
+        assert_eq!(
+            err.render(),
+            [
+                "error: invalid digit found in string",
+                "  --> input.txt:10:5",
+                "   |",
+                "10 | 3   4x",
+                "   |     ^^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn render_without_location() {
+        let err = Error::unsolvable("The lists are empty");
+
+        assert_eq!(err.render(), "error: The lists are empty");
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    convert::Infallible,
+    fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use clap::Args;
+
+use crate::error::{Error, Location, Result};
+
+/// Command line arguments selecting the puzzle input
+#[derive(Args, Debug, Default)]
+pub struct InputArgs {
+    /// Path to the puzzle input, `-` to read from stdin
+    #[arg(long, short, value_name = "PATH")]
+    pub input: Option<InputSource>,
+
+    /// Solve the published example instead of the puzzle input
+    #[arg(long, conflicts_with = "input")]
+    pub example: bool,
+
+    /// Skip lines that do not follow the puzzle grammar instead of rejecting them
+    #[arg(long)]
+    pub lenient: bool,
+}
+
+impl InputArgs {
+    /// The requested input, falling back to `default` when none was given
+    pub fn source_or(&self, default: impl Into<PathBuf>) -> InputSource {
+        self.input
+            .clone()
+            .unwrap_or_else(|| InputSource::File(default.into()))
+    }
+
+    /// Name of the requested input as records show it, `<example>` for the published examples
+    pub fn name_or(&self, default: impl Into<PathBuf>) -> String {
+        if self.example {
+            "<example>".to_string()
+        } else {
+            self.source_or(default).to_string()
+        }
+    }
+
+    pub fn mode(&self) -> ParseMode {
+        ParseMode::from_lenient(self.lenient)
+    }
+}
+
+/// How readers treat lines that do not follow the puzzle grammar
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Every non-blank line must follow the grammar
+    #[default]
+    Strict,
+    /// Lines that do not follow the grammar are skipped
+    Lenient,
+}
+
+impl ParseMode {
+    pub fn from_lenient(lenient: bool) -> Self {
+        if lenient { ParseMode::Lenient } else { ParseMode::Strict }
+    }
+
+    /// Called for a line the grammar did not match, errors unless lenient or the line is blank
+    pub fn reject(self, line_idx: usize, text: &str, expected: &str) -> Result<()> {
+        if self == ParseMode::Lenient || text.trim().is_empty() {
+            return Ok(());
+        }
+
+        Err(Error::parse(format!("Expected {expected}"), Location::line(line_idx, text)))
+    }
+}
+
+/// Where a puzzle input is read from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String> {
+        let result = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        result.map_err(|error| Error::Io {
+            file: self.to_string(),
+            error,
+        })
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dash_is_stdin() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+    }
+
+    #[test]
+    fn anything_else_is_a_file() {
+        assert_eq!(
+            "puzzles/first/input.txt".parse(),
+            Ok(InputSource::File(PathBuf::from("puzzles/first/input.txt")))
+        );
+    }
+
+    #[test]
+    fn falls_back_to_default() {
+        let args = InputArgs::default();
+        assert_eq!(args.source_or("default.txt"), InputSource::File(PathBuf::from("default.txt")));
+        assert_eq!(args.name_or("default.txt"), "default.txt");
+
+        let args = InputArgs {
+            input: Some(InputSource::Stdin),
+            ..InputArgs::default()
+        };
+        assert_eq!(args.source_or("default.txt"), InputSource::Stdin);
+    }
+
+    #[test]
+    fn strict_mode_rejects_non_blank_lines() {
+        let err = ParseMode::Strict.reject(4, "12 x", "two numbers").unwrap_err();
+
+        assert_eq!(err.to_string(), "Expected two numbers at <input>:5:1");
+        assert!(ParseMode::Strict.reject(4, "  ", "two numbers").is_ok());
+        assert!(ParseMode::Lenient.reject(4, "12 x", "two numbers").is_ok());
+    }
+
+    #[test]
+    fn missing_file_is_reported() {
+        let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        let err = source.read().unwrap_err();
+
+        assert_eq!(err.to_string(), "Input file not found: does/not/exist.txt");
+    }
+}
@@ -0,0 +1,9 @@
+pub mod cli;
+pub mod error;
+pub mod input;
+pub mod report;
+pub mod solution;
+
+pub use error::{Error, Location, Result};
+pub use input::ParseMode;
+pub use solution::{Example, Solution};
@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How answers are printed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable lines, with the banner in the day binaries
+    #[default]
+    Plain,
+    /// One aligned row per part
+    Table,
+    /// An array with one object per part
+    Json,
+}
+
+/// The outcome of solving one part of a day
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// Name of the input the part was solved for
+    pub input: String,
+    /// The answer, or the message of the error that prevented it
+    pub answer: Result<String, String>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Schema of a record in the JSON output, every key is always present
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a str>,
+    error: Option<&'a str>,
+    input: &'a str,
+    parse_time_ns: u64,
+    solve_time_ns: u64,
+}
+
+impl<'a> From<&'a Record> for JsonRecord<'a> {
+    fn from(record: &'a Record) -> Self {
+        JsonRecord {
+            day: record.day,
+            part: record.part,
+            answer: record.answer.as_deref().ok(),
+            error: record.answer.as_ref().err().map(String::as_str),
+            input: &record.input,
+            parse_time_ns: record.parse_time.as_nanos() as u64,
+            solve_time_ns: record.solve_time.as_nanos() as u64,
+        }
+    }
+}
+
+pub fn render_json(records: &[Record]) -> String {
+    let records: Vec<JsonRecord> = records.iter().map(JsonRecord::from).collect();
+
+    serde_json::to_string_pretty(&records).expect("records always serialize")
+}
+
+pub fn render_table(records: &[Record]) -> String {
+    let header = ["Day", "Part", "Answer", "Input", "Parse", "Solve"].map(str::to_string);
+    let mut rows = vec![header];
+
+    for record in records {
+        rows.push([
+            record.day.to_string(),
+            record.part.to_string(),
+            match &record.answer {
+                Ok(answer) => answer.clone(),
+                Err(err) => format!("error: {err}"),
+            },
+            record.input.clone(),
+            format!("{:.1?}", record.parse_time),
+            format!("{:.1?}", record.solve_time),
+        ]);
+    }
+
+    let mut widths = [0; 6];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut lines = Vec::with_capacity(rows.len() + 1);
+    for (idx, row) in rows.iter().enumerate() {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| match column {
+                // Numbers and timings read best right aligned
+                0 | 1 | 4 | 5 => format!("{cell:>width$}"),
+                _ => format!("{cell:<width$}"),
+            })
+            .collect();
+        lines.push(cells.join("  ").trim_end().to_string());
+
+        if idx == 0 {
+            lines.push(widths.map(|width| "-".repeat(width)).join("  "));
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                part: 1,
+                input: "input.txt".into(),
+                answer: Ok("11".into()),
+                parse_time: Duration::from_micros(12),
+                solve_time: Duration::from_nanos(500),
+            },
+            Record {
+                day: 6,
+                part: 2,
+                input: "input.txt".into(),
+                answer: Err("Day 6 part 2 has not been solved yet".into()),
+                parse_time: Duration::from_micros(3),
+                solve_time: Duration::ZERO,
+            },
+        ]
+    }
+
+    #[test]
+    fn json_has_stable_keys() {
+        let json: serde_json::Value = serde_json::from_str(&render_json(&records())).unwrap();
+
+        assert_eq!(
+            json[0],
+            serde_json::json!({
+                "day": 1,
+                "part": 1,
+                "answer": "11",
+                "error": null,
+                "input": "input.txt",
+                "parse_time_ns": 12000,
+                "solve_time_ns": 500,
+            })
+        );
+        assert_eq!(json[1]["answer"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn table_aligns_columns() {
+        let table = render_table(&records());
+        let lines: Vec<_> = table.lines().collect();
+
+        assert_eq!(lines[0], "Day  Part  Answer                                       Input       Parse    Solve");
+        assert_eq!(lines[2], "  1     1  11                                           input.txt  12.0µs  500.0ns");
+        assert_eq!(lines[3], "  6     2  error: Day 6 part 2 has not been solved yet  input.txt   3.0µs    0.0ns");
+    }
+}
@@ -0,0 +1,204 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use crate::{
+    error::{Error, Result},
+    input::ParseMode,
+};
+
+/// A single Advent of Code day: the input is parsed once and both parts are solved from it
+pub trait Solution {
+    const DAY: u8;
+
+    /// Number of parts that have been solved
+    const PARTS: u8 = 2;
+
+    /// Published examples, shipped as text fixtures next to the crate
+    const EXAMPLES: &'static [Example] = &[];
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parse the puzzle input, `mode` decides what happens to lines that do not follow the grammar
+    fn parse(input: &str, mode: ParseMode) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+
+    /// Days whose second part has not been solved yet keep this default
+    fn part2(_input: &Self::Input) -> Result<Self::Answer2> {
+        Err(Error::unsolvable(format!("Day {} part 2 has not been solved yet", Self::DAY)))
+    }
+}
+
+/// A published example input together with the answer it produces for one part
+#[derive(Clone, Copy, Debug)]
+pub struct Example {
+    pub part: u8,
+    pub input: &'static str,
+    /// Empty until the answer is filled in, such an example is not checked
+    pub answer: &'static str,
+}
+
+/// Example inputs for `parts`, parts that share an example are grouped so it is parsed once
+pub fn examples_for(
+    day: u8,
+    examples: &'static [Example],
+    parts: RangeInclusive<u8>,
+) -> Result<Vec<(&'static str, Vec<u8>)>> {
+    let mut groups: Vec<(&'static str, Vec<u8>)> = Vec::new();
+
+    for part in parts {
+        let example = examples
+            .iter()
+            .find(|example| example.part == part)
+            .ok_or_else(|| Error::validation(format!("Day {day} part {part} has no example")))?;
+
+        match groups.iter_mut().find(|(input, _)| *input == example.input) {
+            Some((_, parts)) => parts.push(part),
+            None => groups.push((example.input, vec![part])),
+        }
+    }
+
+    Ok(groups)
+}
+
+/// Assert that every example of `part` produces its published answer, used by [`example_tests`].
+/// Examples without an answer yet, as a freshly scaffolded day has, are skipped
+pub fn assert_examples<S: Solution>(part: u8) {
+    if part > S::PARTS {
+        return;
+    }
+
+    let examples: Vec<_> = S::EXAMPLES.iter().filter(|example| example.part == part).collect();
+    assert!(!examples.is_empty(), "Day {} part {part} has no example", S::DAY);
+
+    for example in examples {
+        if example.answer.is_empty() {
+            continue;
+        }
+
+        let answer = S::parse(example.input, ParseMode::Strict).and_then(|input| solve_part::<S>(&input, part));
+
+        match answer {
+            Ok(answer) => assert_eq!(answer, example.answer, "Day {} part {part} example", S::DAY),
+            Err(err) => panic!("Day {} part {part} example failed:\n{}", S::DAY, err.render()),
+        }
+    }
+}
+
+/// Generate a test per part that checks the day's examples
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[cfg(test)]
+        mod example_tests {
+            use super::*;
+
+            #[test]
+            fn part1() {
+                $crate::solution::assert_examples::<$solution>(1);
+            }
+
+            #[test]
+            fn part2() {
+                $crate::solution::assert_examples::<$solution>(2);
+            }
+        }
+    };
+}
+
+/// Solve a single part of an already parsed input and render its answer
+pub fn solve_part<S: Solution>(input: &S::Input, part: u8) -> Result<String> {
+    match part {
+        1 => Ok(S::part1(input)?.to_string()),
+        2 => Ok(S::part2(input)?.to_string()),
+        _ => Err(Error::validation(format!("Day {} has no part {part}", S::DAY))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        const DAY: u8 = 25;
+        const EXAMPLES: &'static [Example] = &[Example {
+            part: 1,
+            input: "a\nb\n",
+            answer: "2",
+        }];
+
+        type Input = usize;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &str, _mode: ParseMode) -> Result<Self::Input> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer1> {
+            Ok(*input)
+        }
+    }
+
+    #[test]
+    fn solve_part_renders_answer() {
+        let input = Unfinished::parse("a\nb\nc\n", ParseMode::Strict).unwrap();
+
+        assert_eq!(solve_part::<Unfinished>(&input, 1).unwrap(), "3");
+        assert!(solve_part::<Unfinished>(&input, 3).is_err());
+    }
+
+    #[test]
+    fn examples_are_grouped_by_input() {
+        let examples = Unfinished::EXAMPLES;
+
+        assert_eq!(examples_for(25, examples, 1..=1).unwrap(), vec![("a\nb\n", vec![1])]);
+        assert!(examples_for(25, examples, 1..=2).is_err());
+
+        assert_examples::<Unfinished>(1);
+    }
+
+    struct Scaffolded;
+
+    impl Solution for Scaffolded {
+        const DAY: u8 = 24;
+        const PARTS: u8 = 1;
+        const EXAMPLES: &'static [Example] = &[Example {
+            part: 1,
+            input: "a\n",
+            answer: "",
+        }];
+
+        type Input = usize;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &str, _mode: ParseMode) -> Result<Self::Input> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(_input: &Self::Input) -> Result<Self::Answer1> {
+            Err(Error::unsolvable("Day 24 part 1 has not been solved yet"))
+        }
+    }
+
+    #[test]
+    fn examples_without_an_answer_are_skipped() {
+        assert_examples::<Scaffolded>(1);
+        assert_examples::<Scaffolded>(2);
+    }
+
+    #[test]
+    fn part2_defaults_to_unsolved() {
+        let input = Unfinished::parse("a\nb\n", ParseMode::Strict).unwrap();
+
+        assert_eq!(Unfinished::part1(&input).unwrap(), 2);
+        assert_eq!(
+            Unfinished::part2(&input).unwrap_err().to_string(),
+            "Day 25 part 2 has not been solved yet"
+        );
+    }
+}
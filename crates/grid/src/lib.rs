@@ -0,0 +1,390 @@
+use std::{
+    fmt, iter,
+    ops::{Index, IndexMut},
+};
+
+use common::{Error, Location, Result};
+use unicode_segmentation::UnicodeSegmentation;
+
+mod point;
+
+pub use point::{Point, Vector};
+
+/// Steps to the orthogonal neighbours, clockwise from the right
+pub const NEIGHBOURS_4: [Vector; 4] = [Vector::RIGHT, Vector::DOWN, Vector::LEFT, Vector::UP];
+
+/// Steps to the orthogonal and diagonal neighbours
+pub const NEIGHBOURS_8: [Vector; 8] = [
+    Vector::new(1, 0),
+    Vector::new(0, 1),
+    Vector::new(1, 1),
+    Vector::new(1, -1),
+    Vector::new(-1, 0),
+    Vector::new(0, -1),
+    Vector::new(-1, -1),
+    Vector::new(-1, 1),
+];
+
+/// Rectangular grid stored row by row, addressed as `(x, y)` from the top left
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` by `height` cells given in row order
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if width.checked_mul(height) != Some(cells.len()) {
+            return Err(Error::validation(format!(
+                "A {width}x{height} grid needs {} cells but {} were given",
+                width.saturating_mul(height),
+                cells.len()
+            )));
+        }
+
+        Ok(Grid { width, height, cells })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid from nested rows, which must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(Error::validation(format!(
+                "All rows must have the same width, expected {width} but row {} has {}",
+                y + 1,
+                row.len()
+            )));
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Grid with a cell per character of `input`, every line being a row. Blank lines are skipped,
+    /// as they are no error anywhere in the puzzle grammar
+    pub fn parse(input: &str, mut map: impl FnMut(char) -> T) -> Result<Self> {
+        Grid::try_parse(input, "any character", |char| Some(map(char)))
+    }
+
+    /// Like [`Grid::parse`], but a character `map` has no cell for is reported as not being `expected`
+    pub fn try_parse(input: &str, expected: &str, mut map: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        Grid::from_lines(
+            input,
+            expected,
+            |line| line.char_indices().map(move |(start, char)| (start, &line[start..start + char.len_utf8()])),
+            |cell| cell.chars().next().and_then(&mut map),
+        )
+    }
+
+    /// Like [`Grid::parse`], with a cell per extended grapheme cluster so that a letter followed by
+    /// combining marks is a single cell and lines are measured the same way
+    pub fn parse_graphemes(input: &str, mut map: impl FnMut(&str) -> T) -> Result<Self> {
+        Grid::from_lines(
+            input,
+            "any character",
+            |line| line.grapheme_indices(true),
+            |cell| Some(map(cell)),
+        )
+    }
+
+    fn from_lines<'s, C: Iterator<Item = (usize, &'s str)>>(
+        input: &'s str,
+        expected: &str,
+        cells_of: impl Fn(&'s str) -> C,
+        mut map: impl FnMut(&'s str) -> Option<T>,
+    ) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for (idx, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut line_width = 0;
+
+            for (start, cell) in cells_of(line) {
+                let cell = map(cell).ok_or_else(|| {
+                    Error::parse(
+                        format!("Expected {expected} but found `{cell}`"),
+                        Location::new(idx, line, start..start + cell.len()),
+                    )
+                })?;
+
+                cells.push(cell);
+                line_width += 1;
+            }
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(Error::validation_at(
+                        format!("All lines must have the same width, expected {width} but found {line_width}"),
+                        Location::line(idx, line),
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point).then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point).then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// Cell at a signed coordinate, `None` when it lies outside the grid on any side
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        self.get(Point::from_signed(x, y)?)
+    }
+
+    /// Point one `step` away from `point`, if that is still inside the grid
+    pub fn offset(&self, point: Point, step: Vector) -> Option<Point> {
+        point.checked_add(step).filter(|&point| self.contains(point))
+    }
+
+    /// Every cell with its position, in row order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (self.point(idx), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let width = self.width;
+
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(idx, cell)| (Point::new(idx % width, idx / width), cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Position of the first cell, in row order, that satisfies `predicate`
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(predicate).map(|idx| self.point(idx))
+    }
+
+    fn point(&self, idx: usize) -> Point {
+        Point::new(idx % self.width, idx / self.width)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.ray(Point::new(x, 0), Vector::DOWN)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Diagonals running down and to the right, from the bottom left corner to the top right one
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let left = (0..self.height).rev().map(|y| Point::new(0, y));
+        let top = (1..self.width).map(|x| Point::new(x, 0));
+
+        left.chain(top).map(|start| self.ray(start, Vector::new(1, 1)))
+    }
+
+    /// Diagonals running down and to the left, from the top left corner to the bottom right one
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let top = (0..self.width).map(|x| Point::new(x, 0));
+        let right = (1..self.height).filter_map(|y| Some(Point::new(self.width.checked_sub(1)?, y)));
+
+        top.chain(right).map(|start| self.ray(start, Vector::new(-1, 1)))
+    }
+
+    /// Cells from `start` onwards, moving by `step` until leaving the grid
+    pub fn ray(&self, start: Point, step: Vector) -> impl Iterator<Item = &T> {
+        let start = self.contains(start).then_some(start);
+
+        iter::successors(start, move |&point| self.offset(point, step)).map(|point| &self[point])
+    }
+
+    /// Cells one of `steps` away from `point` that lie inside the grid
+    pub fn neighbours<'a>(&'a self, point: Point, steps: &'a [Vector]) -> impl Iterator<Item = (Point, &'a T)> {
+        steps
+            .iter()
+            .filter_map(move |&step| self.offset(point, step))
+            .map(|neighbour| (neighbour, &self[neighbour]))
+    }
+
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, &NEIGHBOURS_4)
+    }
+
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, &NEIGHBOURS_8)
+    }
+
+    /// Grid of the same shape with `map` applied to every cell
+    pub fn map<U>(&self, map: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(map).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point} is outside the {width}x{height} grid"))
+    }
+}
+
+/// One line per row, without a trailing newline
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc\ndef\n";
+
+    fn collect<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+        cells.collect()
+    }
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        let grid = Grid::parse(INPUT, |char| char).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), INPUT.trim_end());
+
+        let padded = Grid::parse(&format!("\n{INPUT}\n\n"), |char| char).unwrap();
+        assert_eq!(padded, grid);
+    }
+
+    #[test]
+    fn parse_reports_ragged_lines_and_unexpected_characters() {
+        let err = Grid::parse("abc\nde\n", |char| char).unwrap_err();
+        assert_eq!(err.to_string(), "All lines must have the same width, expected 3 but found 2 at <input>:2:1");
+
+        let err = Grid::try_parse("..\n.x\n", "`.`", |char| (char == '.').then_some(())).unwrap_err();
+        assert_eq!(err.to_string(), "Expected `.` but found `x` at <input>:2:2");
+    }
+
+    #[test]
+    fn graphemes_are_single_cells() {
+        // `e` followed by a combining acute accent next to a precomposed `é`
+        let grid = Grid::parse_graphemes("e\u{301}é\nab\n", str::to_string).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[Point::new(0, 0)], "e\u{301}");
+        assert!(Grid::parse("e\u{301}é\nab\n", |char| char).is_err());
+    }
+
+    #[test]
+    fn access_is_bounds_safe() {
+        let grid = Grid::parse(INPUT, |char| char).unwrap();
+
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(0, isize::MIN), None);
+        assert_eq!(grid.offset(Point::new(0, 0), Vector::LEFT), None);
+        assert_eq!(grid.offset(Point::new(2, 0), Vector::RIGHT), None);
+        assert_eq!(grid.offset(Point::new(0, 0), Vector::new(1, 1)), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn lines_through_the_grid() {
+        let grid = Grid::parse(INPUT, |char| char).unwrap();
+
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.columns().map(collect).collect::<Vec<_>>(), ["ad", "be", "cf"]);
+        assert_eq!(grid.diagonals().map(collect).collect::<Vec<_>>(), ["d", "ae", "bf", "c"]);
+        assert_eq!(grid.anti_diagonals().map(collect).collect::<Vec<_>>(), ["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn neighbourhoods_stay_inside() {
+        let grid = Grid::parse(INPUT, |char| char).unwrap();
+
+        assert_eq!(collect(grid.neighbours4(Point::new(0, 0)).map(|(_, cell)| cell)), "bd");
+        assert_eq!(collect(grid.neighbours8(Point::new(1, 0)).map(|(_, cell)| cell)), "cefad");
+    }
+}
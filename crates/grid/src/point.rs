@@ -0,0 +1,93 @@
+use std::fmt;
+
+/// Position of a cell, `x` counting columns and `y` rows from the top left
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+
+    /// Point at a signed coordinate, `None` when either part is negative
+    pub fn from_signed(x: isize, y: isize) -> Option<Self> {
+        Some(Point::new(usize::try_from(x).ok()?, usize::try_from(y).ok()?))
+    }
+
+    /// Point moved by `vector`, `None` when that would leave the range of `usize`
+    pub fn checked_add(self, vector: Vector) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_add_signed(vector.dx)?,
+            self.y.checked_add_signed(vector.dy)?,
+        ))
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Signed step between two points
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector {
+    pub dx: isize,
+    pub dy: isize,
+}
+
+impl Vector {
+    pub const UP: Vector = Vector::new(0, -1);
+    pub const RIGHT: Vector = Vector::new(1, 0);
+    pub const DOWN: Vector = Vector::new(0, 1);
+    pub const LEFT: Vector = Vector::new(-1, 0);
+
+    pub const fn new(dx: isize, dy: isize) -> Self {
+        Vector { dx, dy }
+    }
+
+    /// Vector `factor` times as long, `None` on overflow
+    pub fn checked_mul(self, factor: isize) -> Option<Self> {
+        Some(Vector::new(self.dx.checked_mul(factor)?, self.dy.checked_mul(factor)?))
+    }
+
+    /// Quarter turn clockwise, with `y` pointing down
+    pub const fn turn_right(self) -> Self {
+        Vector::new(-self.dy, self.dx)
+    }
+
+    pub const fn reversed(self) -> Self {
+        Vector::new(-self.dx, -self.dy)
+    }
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:+}, {:+})", self.dx, self.dy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_is_checked() {
+        assert_eq!(Point::new(0, 3).checked_add(Vector::LEFT), None);
+        assert_eq!(Point::new(usize::MAX, 0).checked_add(Vector::RIGHT), None);
+        assert_eq!(Point::new(2, 3).checked_add(Vector::UP), Some(Point::new(2, 2)));
+        assert_eq!(Point::from_signed(-1, 0), None);
+        assert_eq!(Vector::new(isize::MAX, 0).checked_mul(2), None);
+    }
+
+    #[test]
+    fn turning_right_cycles_through_the_directions() {
+        assert_eq!(Vector::UP.turn_right(), Vector::RIGHT);
+        assert_eq!(Vector::RIGHT.turn_right(), Vector::DOWN);
+        assert_eq!(Vector::DOWN.turn_right(), Vector::LEFT);
+        assert_eq!(Vector::LEFT.turn_right(), Vector::UP);
+    }
+}
@@ -0,0 +1,195 @@
+use common::{Example, Error, ParseMode, Result, Solution, error::parse_at};
+use regex::Regex;
+
+/// Day 1, both parts share the parsed lists
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: include_str!("../example.txt"),
+            answer: "11",
+        },
+        Example {
+            part: 2,
+            input: include_str!("../example.txt"),
+            answer: "31",
+        },
+    ];
+
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str, mode: ParseMode) -> Result<Self::Input> {
+        input.read_lists(mode)
+    }
+
+    /// Sum of the distances between the sorted left and right lists
+    fn part1(lists: &Self::Input) -> Result<Self::Answer1> {
+        Ok(lists
+            .clone()
+            .sort_lists()
+            .calc_distances()?
+            .iter()
+            .sum())
+    }
+
+    /// Similarity score of the left list against the right list
+    fn part2(lists: &Self::Input) -> Result<Self::Answer2> {
+        lists.clone().get_similarity_score()
+    }
+}
+
+common::example_tests!(Day01);
+
+fn calc_distance(a: i32, b: i32) -> u32 {
+    a.abs_diff(b)
+}
+
+/// Sort numbers in ascending order
+fn sort_asc(numbers: impl IntoIterator<Item = i32>) -> Vec<i32> {
+    let mut numbers: Vec<_> = numbers.into_iter().collect();
+    numbers.sort_unstable();
+    numbers
+}
+
+pub trait ReadLists {
+    fn read_lists(&self, mode: ParseMode) -> Result<(Vec<i32>, Vec<i32>)>;
+}
+
+impl ReadLists for str {
+    fn read_lists(&self, mode: ParseMode) -> Result<(Vec<i32>, Vec<i32>)> {
+        let regex = Regex::new(r"^(\d+)\s+(\d+)$").expect("pattern is valid");
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+
+        for (idx, line) in self.lines().enumerate() {
+            if let Some(captures) = regex.captures(line) {
+                let first_match = parse_at::<i32>(idx, line, captures.get(1).expect("group 1").range())?;
+                let second_match = parse_at::<i32>(idx, line, captures.get(2).expect("group 2").range())?;
+                a.push(first_match);
+                b.push(second_match);
+            } else {
+                mode.reject(idx, line, "two numbers separated by whitespace")?;
+            }
+        }
+
+        Ok((a, b))
+    }
+}
+
+pub trait SortLists {
+    fn sort_lists(self) -> (Vec<i32>, Vec<i32>);
+}
+
+impl SortLists for (Vec<i32>, Vec<i32>) {
+    fn sort_lists(self) -> (Vec<i32>, Vec<i32>) {
+        let (a, b) = self;
+        (sort_asc(a), sort_asc(b))
+    }
+}
+
+pub trait CalcDistances {
+    fn calc_distances(&self) -> Result<Vec<u32>>;
+}
+
+impl CalcDistances for (Vec<i32>, Vec<i32>) {
+    fn calc_distances(&self) -> Result<Vec<u32>> {
+        let (a, b) = self;
+        if a.len() != b.len() {
+            return Err(Error::validation(format!(
+                "Lists must be of the same length: {} != {}",
+                a.len(),
+                b.len()
+            )));
+        }
+
+        Ok(a.iter()
+            .zip(b.iter())
+            .map(|(&x, &y)| calc_distance(x, y))
+            .collect())
+    }
+}
+
+pub trait GetSimilarityScore {
+    fn get_similarity_score(self) -> Result<u32>;
+}
+
+impl GetSimilarityScore for (Vec<i32>, Vec<i32>) {
+    /// Every left number multiplied by how often it appears in the right list. Empty lists mean
+    /// the input held no pairs at all, which is reported rather than scored as zero
+    fn get_similarity_score(self) -> Result<u32> {
+        let (left, right) = self.sort_lists();
+
+        if left.is_empty() || right.is_empty() {
+            return Err(Error::unsolvable("Both lists must contain at least one number"));
+        }
+
+        let mut score = 0;
+        let mut right_idx = 0;
+
+        for &number in &left {
+            while right_idx < right.len() && right[right_idx] < number {
+                right_idx += 1;
+            }
+
+            let occurrences = right[right_idx..]
+                .iter()
+                .take_while(|&&other| other == number)
+                .count();
+
+            score += number as u32 * occurrences as u32;
+        }
+
+        Ok(score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calc_distance_b_bigger_than_a() {
+        assert_eq!(calc_distance(1, 3), 2);
+    }
+
+    #[test]
+    fn calc_distance_a_bigger_than_b() {
+        assert_eq!(calc_distance(3, 1), 2);
+    }
+
+    #[test]
+    fn calc_distance_a_equal_to_b() {
+        assert_eq!(calc_distance(1, 1), 0);
+    }
+
+    #[test]
+    fn sort_asc_numbers() {
+        let numbers = vec![3, 1, 2];
+        assert_eq!(sort_asc(numbers), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn similarity_score_counts_duplicates_and_missing_values() {
+        let example = (vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3]);
+        assert_eq!(example.get_similarity_score().unwrap(), 31);
+
+        // 2 is on the right three times and counted for both left 2s, 1 and 7 are not on the right
+        let lists = (vec![7, 2, 1, 2], vec![2, 5, 2, 2]);
+        assert_eq!(lists.get_similarity_score().unwrap(), 12);
+
+        assert!((Vec::new(), Vec::new()).get_similarity_score().is_err());
+    }
+
+    #[test]
+    fn read_lists_rejects_malformed_line_unless_lenient() {
+        let input = "3   4\n4   \n\n9   3\n";
+
+        assert!(input.read_lists(ParseMode::Strict).is_err());
+        assert_eq!(input.read_lists(ParseMode::Lenient).unwrap(), (vec![3, 9], vec![4, 3]));
+    }
+}
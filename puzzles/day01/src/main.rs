@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day01::Day01;
+
+fn main() -> ExitCode {
+    common::cli::run::<Day01>("./puzzles/day01/input.txt")
+}
@@ -0,0 +1,260 @@
+use common::{Example, ParseMode, Result, Solution, error::parse_at};
+use regex::Regex;
+
+/// Day 2, both parts share the parsed reports
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: include_str!("../example.txt"),
+            answer: "2",
+        },
+        Example {
+            part: 2,
+            input: include_str!("../example.txt"),
+            answer: "4",
+        },
+    ];
+
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str, mode: ParseMode) -> Result<Self::Input> {
+        input.read_reports(mode)
+    }
+
+    /// Number of reports that are safe without the problem dampener
+    fn part1(reports: &Self::Input) -> Result<Self::Answer1> {
+        Ok(reports.iter().filter(|report| is_safe_report(report)).count() as u32)
+    }
+
+    /// Number of reports that are safe with the problem dampener applied
+    fn part2(reports: &Self::Input) -> Result<Self::Answer2> {
+        Ok(reports.count_valid())
+    }
+}
+
+common::example_tests!(Day02);
+
+pub trait ReadReports {
+    fn read_reports(&self, mode: ParseMode) -> Result<Vec<Vec<i32>>>;
+}
+
+impl ReadReports for str {
+    fn read_reports(&self, mode: ParseMode) -> Result<Vec<Vec<i32>>> {
+        let regex = Regex::new(r"\d+").expect("pattern is valid");
+        let grammar = Regex::new(r"^\d+(?:\s+\d+)*$").expect("pattern is valid");
+        let mut reports = Vec::new();
+
+        for (idx, line) in self.lines().enumerate() {
+            if !grammar.is_match(line) {
+                mode.reject(idx, line, "numbers separated by whitespace")?;
+                continue;
+            }
+
+            let report = regex
+                .find_iter(line)
+                .map(|measurement| parse_at::<i32>(idx, line, measurement.range()))
+                .collect::<Result<Vec<i32>>>()?;
+
+            reports.push(report);
+        }
+
+        Ok(reports)
+    }
+}
+
+fn is_safe_report(report: &[i32]) -> bool {
+    let mut last_measurement: Option<i32> = None;
+    let mut is_incrementing: Option<bool> = None;
+
+    for &measurement in report {
+        if let Some(last) = last_measurement {
+            let diff = measurement - last;
+
+            if !(1..=3).contains(&diff.abs()) {
+                return false;
+            }
+
+            match last.cmp(&measurement) {
+                std::cmp::Ordering::Less => {
+                    if let Some(false) = is_incrementing {
+                        return false;
+                    }
+
+                    is_incrementing = Some(true);
+                },
+                std::cmp::Ordering::Greater => {
+                    if let Some(true) = is_incrementing {
+                        return false;
+                    }
+
+                    is_incrementing = Some(false);
+                },
+                _ => {}
+            }
+        }
+
+        last_measurement = Some(measurement);
+    }
+
+    true
+}
+
+pub trait ValidateReportsWithProblemDampener {
+    fn count_valid(&self) -> u32;
+}
+
+impl ValidateReportsWithProblemDampener for [Vec<i32>] {
+    fn count_valid(&self) -> u32 {
+        let mut count: u32 = 0;
+
+        for report in self {
+            if is_valid_report(report, false) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+}
+
+fn is_valid_report(report: &[i32], dampening_applied: bool) -> bool {
+    let mut is_incrementing: Option<bool> = None;
+
+    for (i, curr) in report.iter().enumerate() {
+        if i == 0 {
+            continue;
+        }
+
+        let prev = report[i - 1];
+        let diff = *curr - prev;
+
+        if !(1..=3).contains(&diff.abs()) {
+            if !dampening_applied {
+                let mut new_report: Vec<i32> = report.into();
+                new_report.remove(i - 1);
+                return is_valid_report(&new_report, true);
+            }
+
+            return false;
+        }
+
+        match prev.cmp(curr) {
+            std::cmp::Ordering::Less => {
+                if is_incrementing.is_some_and(|v| !v) {
+                    if !dampening_applied {
+                        let mut new_report: Vec<i32> = report.into();
+                        new_report.remove(i - 1);
+                        return is_valid_report(&new_report, true);
+                    }
+
+                    return false;
+                }
+
+                is_incrementing = Some(true);
+            },
+            std::cmp::Ordering::Greater => {
+                if is_incrementing.is_some_and(|v| v) {
+                    if !dampening_applied {
+                        let mut new_report: Vec<i32> = report.into();
+                        new_report.remove(i - 1);
+                        return is_valid_report(&new_report, true);
+                    }
+
+                    return false;
+                }
+
+                is_incrementing = Some(false);
+            },
+            _ => {}
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_report_with_dampening() {
+        // 7 6 4 2 1
+        let report = vec![7, 6, 4, 2, 1];
+        assert!(is_valid_report(&report, false));
+
+        // 1 2 7 8 9
+        let report = vec![1, 2, 7, 8, 9];
+        assert!(!is_valid_report(&report, false));
+        
+        // 9 7 6 2 1
+        let report = vec![9, 7, 6, 2, 1];
+        assert!(!is_valid_report(&report, false));
+        
+        // 1 3 2 4 5
+        let report = vec![1, 3, 2, 4, 5];
+        assert!(is_valid_report(&report, false));
+        
+        // 8 6 4 4 1
+        let report = vec![8, 6, 4, 4, 1];
+        assert!(is_valid_report(&report, false));        
+        
+        // 1 3 6 7 9
+        let report = vec![1, 3, 6, 7, 9];
+        assert!(is_valid_report(&report, false));
+    }
+
+    #[test]
+    fn test_is_valid_report_without_dampening() {
+        // 7 6 4 2 1
+        let report = vec![7, 6, 4, 2, 1];
+        assert!(is_valid_report(&report, true));
+
+        // 1 2 7 8 9
+        let report = vec![1, 2, 7, 8, 9];
+        assert!(!is_valid_report(&report, true));
+
+        // 9 7 6 2 1
+        let report = vec![9, 7, 6, 2, 1];
+        assert!(!is_valid_report(&report, true));
+
+        // 1 3 2 4 5
+        let report = vec![1, 3, 2, 4, 5];
+        assert!(!is_valid_report(&report, true));
+
+        // 8 6 4 4 1
+        let report = vec![8, 6, 4, 4, 1];
+        assert!(!is_valid_report(&report, true));
+
+        // 1 3 6 7 9
+        let report = vec![1, 3, 6, 7, 9];
+        assert!(is_valid_report(&report, true));
+    }
+
+    #[test]
+    fn test_is_safe_report() {
+        assert!(is_safe_report(&[7, 6, 4, 2, 1]));
+        assert!(!is_safe_report(&[1, 2, 7, 8, 9]));
+        assert!(!is_safe_report(&[9, 7, 6, 2, 1]));
+        assert!(!is_safe_report(&[1, 3, 2, 4, 5]));
+        assert!(!is_safe_report(&[8, 6, 4, 4, 1]));
+        assert!(is_safe_report(&[1, 3, 6, 7, 9]));
+    }
+
+    #[test]
+    fn test_read_reports_modes() {
+        let input = "7 6 4 2 1\nfoo\n\n1 2 7 8 9\n";
+
+        let err = input.read_reports(ParseMode::Strict).unwrap_err();
+        assert_eq!(err.location().map(|location| location.line), Some(2));
+
+        let reports = Day02::parse(input, ParseMode::Lenient).unwrap();
+        assert_eq!(reports, vec![vec![7, 6, 4, 2, 1], vec![1, 2, 7, 8, 9]]);
+        assert_eq!(Day02::part1(&reports).unwrap(), 1);
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day02::Day02;
+
+fn main() -> ExitCode {
+    common::cli::run::<Day02>("./puzzles/day02/input.txt")
+}
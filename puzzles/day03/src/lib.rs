@@ -0,0 +1,96 @@
+use common::{Example, ParseMode, Result, Solution, error::parse_at};
+use regex::Regex;
+
+/// Day 3, both parts share the parsed instructions
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: include_str!("../example.txt"),
+            answer: "161",
+        },
+        Example {
+            part: 2,
+            input: include_str!("../example2.txt"),
+            answer: "48",
+        },
+    ];
+
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    /// Any text is valid input, so `mode` has nothing to reject
+    fn parse(input: &str, _mode: ParseMode) -> Result<Self::Input> {
+        input.read_instructions()
+    }
+
+    /// Sum of all uncorrupted multiplications
+    fn part1(instructions: &Self::Input) -> Result<Self::Answer1> {
+        Ok(instructions
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Mul(a, b) => a * b,
+                _ => 0,
+            })
+            .sum())
+    }
+
+    /// Sum of all multiplications enabled by `do()`/`don't()`
+    fn part2(instructions: &Self::Input) -> Result<Self::Answer2> {
+        let mut enabled: bool = true;
+        let mut sum = 0;
+
+        for instruction in instructions {
+            match instruction {
+                Instruction::Do => enabled = true,
+                Instruction::Dont => enabled = false,
+                Instruction::Mul(a, b) if enabled => sum += a * b,
+                Instruction::Mul(..) => {}
+            }
+        }
+
+        Ok(sum)
+    }
+}
+
+common::example_tests!(Day03);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+pub trait ReadInstructions {
+    fn read_instructions(&self) -> Result<Vec<Instruction>>;
+}
+
+impl ReadInstructions for str {
+    fn read_instructions(&self) -> Result<Vec<Instruction>> {
+        let regex = Regex::new(r"(?:mul\((\d{1,3}),(\d{1,3})\)|don't\(\)|do\(\))").expect("pattern is valid");
+        let mut instructions = Vec::new();
+
+        for (idx, line) in self.lines().enumerate() {
+            let matched = regex.captures_iter(line);
+
+            for instruction in matched {
+                match (instruction.get(1), instruction.get(2)) {
+                    (Some(first), Some(second)) => {
+                        let first = parse_at::<i32>(idx, line, first.range())?;
+                        let second = parse_at::<i32>(idx, line, second.range())?;
+                        instructions.push(Instruction::Mul(first, second));
+                    }
+                    _ if &instruction[0] == "don't()" => instructions.push(Instruction::Dont),
+                    _ => instructions.push(Instruction::Do),
+                }
+            }
+        }
+
+        Ok(instructions)
+    }
+}
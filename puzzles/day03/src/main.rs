@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day03::Day03;
+
+fn main() -> ExitCode {
+    common::cli::run::<Day03>("./puzzles/day03/input.txt")
+}
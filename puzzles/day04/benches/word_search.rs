@@ -0,0 +1,80 @@
+use std::{hint::black_box, iter};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use day04::{Board, WordSearch, WordSearchBuilder};
+use grid::{NEIGHBOURS_8, Point, Vector};
+
+/// The example tiled `repeat` times in both directions
+fn board(repeat: usize) -> String {
+    let example = include_str!("../example.txt");
+
+    (0..repeat)
+        .flat_map(|_| example.lines())
+        .map(|line| line.repeat(repeat) + "\n")
+        .collect()
+}
+
+fn word_search(input: &str) -> WordSearch<'static> {
+    WordSearchBuilder::parse(input).unwrap().with_word("XMAS").build().unwrap()
+}
+
+/// Copy of the matcher that the byte path replaced, as the point of comparison. It collects the
+/// word and the matched cells anew for every start and direction
+fn baseline_count(board: &Board, word: &str) -> u32 {
+    let mut count = 0;
+
+    for y in 0..board.height() {
+        for x in 0..board.width() {
+            for velocity in NEIGHBOURS_8 {
+                let word: Vec<char> = word.chars().collect();
+
+                if let Some(matched) = baseline_match(board, word, Point::new(x, y), velocity) {
+                    black_box(matched);
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+fn baseline_match(board: &Board, mut word: Vec<char>, start: Point, velocity: Vector) -> Option<Vec<Point>> {
+    let mut position = Some(start);
+    let mut positions = Vec::with_capacity(word.len());
+
+    while let Some(char) = word.pop() {
+        let point = position?;
+
+        if !board.get_position(point)?.letter().chars().eq(iter::once(char)) {
+            return None;
+        }
+
+        positions.push(point);
+        position = point.checked_add(velocity);
+    }
+
+    Some(positions)
+}
+
+fn count_matches(c: &mut Criterion) {
+    for repeat in [14, 50] {
+        let input = board(repeat);
+        let mut group = c.benchmark_group(format!("count_matches/{}x{}", repeat * 10, repeat * 10));
+
+        let mut search = word_search(&input);
+        group.bench_function("bytes", |b| b.iter(|| black_box(search.count_matches())));
+
+        let mut search = word_search(&input);
+        group.bench_function("letters", |b| b.iter(|| black_box(search.count_matches_by_char())));
+
+        let board = Board::parse(&input).unwrap();
+        assert_eq!(baseline_count(&board, "XMAS"), word_search(&input).count_matches());
+        group.bench_function("baseline", |b| b.iter(|| black_box(baseline_count(&board, "XMAS"))));
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, count_matches);
+criterion_main!(benches);
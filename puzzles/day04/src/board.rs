@@ -0,0 +1,370 @@
+use std::{
+    borrow::Cow,
+    env,
+    fmt::Write,
+    io::{self, IsTerminal},
+};
+
+use common::{Error, ParseMode, Result};
+use grid::{Grid, Point, Vector};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// How matched letters stand out when a board is rendered
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Highlight {
+    /// Matched letters in bold green, every other letter dimmed
+    Ansi,
+    /// Matched letters as they are, every other cell as `.` like the puzzle text does
+    Plain,
+}
+
+impl Highlight {
+    /// Colour when stdout is a terminal and `NO_COLOR` is not set
+    pub fn detect() -> Self {
+        if io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()) {
+            Highlight::Ansi
+        } else {
+            Highlight::Plain
+        }
+    }
+}
+
+/// What lies beyond the edges of a board
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Boundary {
+    /// Nothing, a word cannot run off the board
+    #[default]
+    Clip,
+    /// The opposite edge, as on a torus
+    Wrap,
+    /// The board reflected in its edge, so the edge cells are read twice when running past them
+    Mirror,
+}
+
+impl Boundary {
+    /// Coordinate reached from `coordinate` by `delta` on a side of `length` cells
+    fn resolve(self, coordinate: usize, delta: isize, length: usize) -> Option<usize> {
+        let target = coordinate.checked_add_signed(delta);
+
+        match self {
+            Boundary::Clip => target.filter(|&target| target < length),
+            Boundary::Wrap | Boundary::Mirror if length == 0 => None,
+            Boundary::Wrap => {
+                let target = isize::try_from(coordinate).ok()?.checked_add(delta)?;
+
+                Some(target.rem_euclid(isize::try_from(length).ok()?) as usize)
+            }
+            Boundary::Mirror => {
+                let target = isize::try_from(coordinate).ok()?.checked_add(delta)?;
+                let period = isize::try_from(length).ok()?.checked_mul(2)?;
+                let folded = target.rem_euclid(period) as usize;
+
+                Some(if folded < length { folded } else { 2 * length - 1 - folded })
+            }
+        }
+    }
+}
+
+/// Whether letters that differ only in case match each other
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Case {
+    #[default]
+    Sensitive,
+    /// Letters are compared in lower case, so `É` matches `é` and `Σ` matches both `σ` and `ς`
+    Insensitive,
+}
+
+impl Case {
+    /// The form of a single letter that is compared
+    pub fn fold(self, letter: &str) -> Cow<'_, str> {
+        match self {
+            Case::Sensitive => Cow::Borrowed(letter),
+            // Lower casing letter by letter, a whole word would end in a final sigma
+            Case::Insensitive => Cow::Owned(letter.to_lowercase().replace('ς', "σ")),
+        }
+    }
+
+    /// The letters of `word`, one per grapheme cluster as on a board, in the form that is compared
+    pub fn letters(self, word: &str) -> Vec<String> {
+        word.graphemes(true).map(|letter| self.fold(letter).into_owned()).collect()
+    }
+}
+
+#[derive(Clone)]
+pub struct Board {
+    grid: Grid<BoardPosition>,
+    boundary: Boundary,
+    case: Case,
+}
+
+impl Board {
+    pub fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    pub fn case(&self) -> Case {
+        self.case
+    }
+
+    /// Whether every cell can be read and nothing lies beyond the edges
+    pub fn is_plain(&self) -> bool {
+        self.boundary == Boundary::Clip && self.grid.iter().all(|(_, position)| !position.blocked)
+    }
+
+    /// Position at `point`, `None` when it lies outside the board or is blocked
+    pub fn get_position(&self, point: Point) -> Option<&BoardPosition> {
+        self.grid.get(point).filter(|position| !position.blocked)
+    }
+
+    /// Point reached from `point` by `step`, following the boundary past the edges
+    pub fn step(&self, point: Point, step: Vector) -> Option<Point> {
+        Some(Point::new(
+            self.boundary.resolve(point.x, step.dx, self.width())?,
+            self.boundary.resolve(point.y, step.dy, self.height())?,
+        ))
+    }
+
+    /// Points from `start` onwards in `direction`, ending at the edge of a clipped board and never
+    /// on the others. Every point is stepped to from `start`, so a mirrored walk turns back at the edge
+    pub fn walk(&self, start: Point, direction: Vector) -> impl Iterator<Item = Point> + '_ {
+        (0..).map_while(move |steps| self.step(start, direction.checked_mul(steps)?))
+    }
+
+    /// The board with the positions marked as matched highlighted, one line per row
+    pub fn render(&self, highlight: Highlight) -> String {
+        let mut rendered = String::new();
+
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                rendered.push('\n');
+            }
+
+            for position in row {
+                let letter = &position.letter;
+
+                // Writing to a `String` cannot fail
+                let _ = match (highlight, position.matches) {
+                    (Highlight::Ansi, true) => write!(rendered, "\x1b[1;32m{letter}\x1b[0m"),
+                    (Highlight::Ansi, false) => write!(rendered, "\x1b[2m{letter}\x1b[0m"),
+                    (Highlight::Plain, true) => write!(rendered, "{letter}"),
+                    (Highlight::Plain, false) => write!(rendered, "."),
+                };
+            }
+        }
+
+        rendered
+    }
+
+    /// Mark the first `length` points of [`Board::walk`] as matched
+    pub(crate) fn mark_walk(&mut self, start: Point, direction: Vector, length: usize) {
+        for steps in 0..length as isize {
+            if let Some(point) = direction.checked_mul(steps).and_then(|step| self.step(start, step)) {
+                self.grid[point].matches = true;
+            }
+        }
+    }
+
+    pub(crate) fn set_matched(&mut self, positions: impl IntoIterator<Item = Point>) {
+        for point in positions {
+            if let Some(board_position) = self.grid.get_mut(point) {
+                board_position.matches = true;
+            }
+        }
+    }
+}
+
+/// Cell of a board, holding one letter: a grapheme cluster such as `é` written as `e` and a
+/// combining accent
+#[derive(Clone)]
+pub struct BoardPosition {
+    letter: Box<str>,
+    /// The letter as it is compared, folded when the board ignores case
+    key: Box<str>,
+    matches: bool,
+    /// A hole in the board, no word runs through it
+    blocked: bool,
+}
+
+impl BoardPosition {
+    fn new(letter: &str) -> Self {
+        BoardPosition {
+            letter: letter.into(),
+            key: letter.into(),
+            matches: false,
+            blocked: false,
+        }
+    }
+
+    pub fn letter(&self) -> &str {
+        &self.letter
+    }
+
+    /// The letter in the form compared against the letters of a word
+    pub(crate) fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Whether the cell holds `letter`, which must already be folded with the case of the board
+    pub(crate) fn matches(&self, letter: &str) -> bool {
+        *self.key == *letter
+    }
+}
+
+impl Board {
+    /// One cell per grapheme cluster, so every line must hold the same number of them
+    pub fn parse(input: &str) -> Result<Self> {
+        Board::parse_with(input, ParseMode::Strict)
+    }
+
+    /// Like [`Board::parse`], skipping lines that are wider or narrower than the first one when
+    /// lenient
+    pub fn parse_with(input: &str, mode: ParseMode) -> Result<Self> {
+        let input = match mode {
+            ParseMode::Strict => Cow::Borrowed(input),
+            ParseMode::Lenient => {
+                let width = |line: &str| line.graphemes(true).count();
+                let mut lines = input.lines().filter(|line| !line.trim().is_empty()).peekable();
+                let first = lines.peek().map(|line| width(line));
+
+                Cow::Owned(lines.filter(|line| Some(width(line)) == first).collect::<Vec<_>>().join("\n"))
+            }
+        };
+
+        Ok(Board {
+            grid: Grid::parse_graphemes(&input, BoardPosition::new)?,
+            boundary: Boundary::default(),
+            case: Case::default(),
+        })
+    }
+
+    pub fn with_boundary(mut self, boundary: Boundary) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn with_case(mut self, case: Case) -> Self {
+        self.case = case;
+
+        for (_, position) in self.grid.iter_mut() {
+            position.key = case.fold(&position.letter).into();
+        }
+
+        self
+    }
+
+    /// Block every cell that is `true` in `mask`, which must have the size of the board
+    pub fn with_mask(mut self, mask: &Grid<bool>) -> Result<Self> {
+        if (mask.width(), mask.height()) != (self.width(), self.height()) {
+            return Err(Error::validation(format!(
+                "The mask is {}x{} but the board is {}x{}",
+                mask.width(),
+                mask.height(),
+                self.width(),
+                self.height()
+            )));
+        }
+
+        for (point, &blocked) in mask.iter() {
+            self.grid[point].blocked |= blocked;
+        }
+
+        Ok(self)
+    }
+
+    /// Block every cell holding `hole`, for boards drawn with gaps
+    pub fn with_holes(mut self, hole: char) -> Self {
+        let hole = hole.to_string();
+
+        for (_, position) in self.grid.iter_mut() {
+            position.blocked |= *position.letter == *hole;
+        }
+
+        self
+    }
+
+    /// Board holding one letter per cell, each a single grapheme cluster
+    pub(crate) fn from_letters(letters: &Grid<String>) -> Self {
+        Board {
+            grid: letters.map(|letter| BoardPosition::new(letter)),
+            boundary: Boundary::default(),
+            case: Case::default(),
+        }
+    }
+
+    pub fn from_vecs(board: Vec<Vec<char>>) -> Result<Self> {
+        let rows = board
+            .into_iter()
+            .map(|row| row.into_iter().map(|char| BoardPosition::new(char.encode_utf8(&mut [0; 4]))).collect())
+            .collect();
+
+        Ok(Board {
+            grid: Grid::from_rows(rows)?,
+            boundary: Boundary::default(),
+            case: Case::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boundaries_resolve_steps_past_the_edges() {
+        let step = |boundary: Boundary, delta| (0..4).map(|x| boundary.resolve(x, delta, 4)).collect::<Vec<_>>();
+
+        assert_eq!(step(Boundary::Clip, 1), [Some(1), Some(2), Some(3), None]);
+        assert_eq!(step(Boundary::Wrap, 1), [Some(1), Some(2), Some(3), Some(0)]);
+        assert_eq!(step(Boundary::Wrap, -1), [Some(3), Some(0), Some(1), Some(2)]);
+        assert_eq!(step(Boundary::Mirror, 1), [Some(1), Some(2), Some(3), Some(3)]);
+        assert_eq!(step(Boundary::Mirror, -2), [Some(1), Some(0), Some(0), Some(1)]);
+        assert_eq!(Boundary::Wrap.resolve(0, 1, 0), None);
+    }
+
+    #[test]
+    fn blocked_cells_cannot_be_read() {
+        let board = Board::parse("ab\n#c\n").unwrap().with_holes('#');
+        let mask = Grid::parse("x.\n..\n", |char| char == 'x').unwrap();
+        let masked = board.clone().with_mask(&mask).unwrap();
+
+        assert!(board.get_position(Point::new(0, 1)).is_none());
+        assert!(board.get_position(Point::new(0, 0)).is_some());
+        assert!(masked.get_position(Point::new(0, 0)).is_none());
+        assert!(!masked.is_plain());
+        assert!(board.with_mask(&Grid::filled(3, 2, false)).is_err());
+    }
+
+    #[test]
+    fn parse_modes() {
+        let example = format!("{}\n", include_str!("../example.txt"));
+
+        for mode in [ParseMode::Strict, ParseMode::Lenient] {
+            let board = Board::parse_with(&example, mode).unwrap();
+            assert_eq!((board.width(), board.height()), (10, 10), "{mode:?}");
+        }
+
+        let ragged = "abc\nde\nfgh\n";
+        assert!(Board::parse_with(ragged, ParseMode::Strict).is_err());
+        assert_eq!(Board::parse_with(ragged, ParseMode::Lenient).unwrap().height(), 2);
+    }
+
+    #[test]
+    fn cells_are_grapheme_clusters() {
+        // Decomposed `é` and the flag of Greece are one cell each
+        let board = Board::parse("e\u{301}🇬🇷Σ\nabc\n").unwrap().with_case(Case::Insensitive);
+
+        assert_eq!((board.width(), board.height()), (3, 2));
+        assert_eq!(board.get_position(Point::new(0, 0)).unwrap().letter(), "e\u{301}");
+        assert!(board.get_position(Point::new(2, 0)).unwrap().matches("σ"));
+        assert_eq!(Case::Insensitive.letters("ΟΔΟΣ"), ["ο", "δ", "ο", "σ"]);
+        assert!(Board::parse("e\u{301}b\nabc\n").is_err());
+    }
+}
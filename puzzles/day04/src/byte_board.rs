@@ -0,0 +1,107 @@
+use std::ops::Range;
+
+use grid::{Point, Vector};
+
+use crate::board::Board;
+
+/// The board as one byte per cell in row order, so a step in a direction is a fixed stride
+pub(crate) struct ByteBoard {
+    width: usize,
+    height: usize,
+    bytes: Vec<u8>,
+}
+
+impl ByteBoard {
+    /// `None` when the board holds letters outside ASCII, has blocked cells or does not clip
+    pub(crate) fn new(board: &Board) -> Option<Self> {
+        if !board.is_plain() {
+            return None;
+        }
+
+        let mut bytes = Vec::with_capacity(board.width() * board.height());
+
+        for y in 0..board.height() {
+            for x in 0..board.width() {
+                let &[byte] = board.get_position(Point::new(x, y))?.key().as_bytes() else {
+                    return None;
+                };
+                bytes.push(Some(byte).filter(u8::is_ascii)?);
+            }
+        }
+
+        Some(ByteBoard {
+            width: board.width(),
+            height: board.height(),
+            bytes,
+        })
+    }
+
+    /// Number of times `word` reads from a cell along one of `directions`, calling `found` for each
+    pub(crate) fn count(&self, word: &[u8], directions: &[Vector], mut found: impl FnMut(Point, Vector)) -> u32 {
+        let Some((&first, rest)) = word.split_first() else {
+            return 0;
+        };
+        let reach = rest.len() as isize;
+        let mut count = 0;
+
+        for &direction in directions {
+            // Only starts that keep the whole word on the board, so no step inside the loop can leave it
+            let (Some(xs), Some(ys)) = (
+                starts(self.width, direction.dx * reach),
+                starts(self.height, direction.dy * reach),
+            ) else {
+                continue;
+            };
+            let stride = direction.dy * self.width as isize + direction.dx;
+
+            for y in ys {
+                for x in xs.clone() {
+                    let start = y * self.width + x;
+
+                    if self.bytes[start] != first {
+                        continue;
+                    }
+
+                    let matched = rest.iter().enumerate().all(|(idx, &byte)| {
+                        self.bytes[start.wrapping_add_signed((idx as isize + 1) * stride)] == byte
+                    });
+
+                    if matched {
+                        found(Point::new(x, y), direction);
+                        count += 1;
+                    }
+                }
+            }
+        }
+
+        count
+    }
+}
+
+/// Coordinates along a side of `length` cells from which moving `reach` cells stays on the board
+fn starts(length: usize, reach: isize) -> Option<Range<usize>> {
+    let start = reach.min(0).unsigned_abs();
+    let end = length.checked_sub(reach.max(0).unsigned_abs())?;
+
+    (start < end).then_some(start..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_keep_the_word_on_the_board() {
+        assert_eq!(starts(10, 3), Some(0..7));
+        assert_eq!(starts(10, -3), Some(3..10));
+        assert_eq!(starts(10, 0), Some(0..10));
+        assert_eq!(starts(3, 3), None);
+        assert_eq!(starts(2, -5), None);
+    }
+
+    #[test]
+    fn only_ascii_boards_are_converted() {
+        assert!(ByteBoard::new(&Board::parse("ab\ncd\n").unwrap()).is_some());
+        assert!(ByteBoard::new(&Board::parse("aé\ncd\n").unwrap()).is_none());
+    }
+}
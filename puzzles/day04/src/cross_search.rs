@@ -0,0 +1,127 @@
+use common::Result;
+
+use crate::{
+    board::Board,
+    pattern::{Pattern, PatternSet, Symmetry},
+};
+
+/// Two `MAS` crossing on their `A`, either of them may be written backwards
+pub const X_MAS: &str = "M.S/.A./M.S";
+
+pub struct CrossSearch {
+    board: Board,
+    patterns: PatternSet,
+}
+
+impl CrossSearch {
+    pub fn parse(input: &str) -> Result<Self> {
+        let board = Board::parse(input)?;
+
+        Ok(CrossSearch::new(board))
+    }
+
+    pub fn new(board: Board) -> Self {
+        let patterns = Pattern::parse(X_MAS)
+            .expect("pattern is valid")
+            .with_symmetry(Symmetry::Rotations);
+
+        CrossSearch { board, patterns }
+    }
+
+    pub fn count_matches(&mut self) -> u32 {
+        self.patterns.count(&self.board) as u32
+    }
+
+    pub fn from_vecs(board: Vec<Vec<char>>) -> Result<Self> {
+        Ok(CrossSearch::new(Board::from_vecs(board)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Boundary;
+
+    #[test]
+    fn cross_search_3x3_var1() {
+        let board: Vec<Vec<char>> = vec![vec!['M', '.', 'S'], vec!['.', 'A', '.'], vec![
+            'M', '.', 'S',
+        ]];
+
+        let mut word_search = CrossSearch::from_vecs(board).unwrap();
+        let count = word_search.count_matches();
+
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn cross_search_3x3_var2() {
+        let board: Vec<Vec<char>> = vec![vec!['S', '.', 'M'], vec!['.', 'A', '.'], vec![
+            'S', '.', 'M',
+        ]];
+
+        let mut word_search = CrossSearch::from_vecs(board).unwrap();
+        let count = word_search.count_matches();
+
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn cross_search_3x3_var3() {
+        let board: Vec<Vec<char>> = vec![vec!['S', '.', 'S'], vec!['.', 'A', '.'], vec![
+            'M', '.', 'M',
+        ]];
+
+        let mut word_search = CrossSearch::from_vecs(board).unwrap();
+        let count = word_search.count_matches();
+
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn cross_search_3x3_var4() {
+        let board: Vec<Vec<char>> = vec![vec!['M', '.', 'M'], vec!['.', 'A', '.'], vec![
+            'S', '.', 'S',
+        ]];
+
+        let mut word_search = CrossSearch::from_vecs(board).unwrap();
+        let count = word_search.count_matches();
+
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn cross_search_example() {
+        let board: Vec<Vec<char>> = vec![
+            vec!['.', 'M', '.', 'S', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', 'A', '.', '.', 'M', 'S', 'M', 'S', '.'],
+            vec!['.', 'M', '.', 'S', '.', 'M', 'A', 'A', '.', '.'],
+            vec!['.', '.', 'A', '.', 'A', 'S', 'M', 'S', 'M', '.'],
+            vec!['.', 'M', '.', 'S', '.', 'M', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['S', '.', 'S', '.', 'S', '.', 'S', '.', 'S', '.'],
+            vec!['.', 'A', '.', 'A', '.', 'A', '.', 'A', '.', '.'],
+            vec!['M', '.', 'M', '.', 'M', '.', 'M', '.', 'M', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+        ];
+
+        let mut word_search = CrossSearch::from_vecs(board).unwrap();
+        let count = word_search.count_matches();
+
+        assert_eq!(count, 9);
+    }
+
+    #[test]
+    fn cross_search_across_the_edges() {
+        let board = Board::parse("A..\n.MS\n.MS\n").unwrap();
+
+        assert_eq!(CrossSearch::new(board.clone()).count_matches(), 0);
+        assert_eq!(CrossSearch::new(board.clone().with_boundary(Boundary::Wrap)).count_matches(), 1);
+
+        let holed = Board::parse("M.S\n.A.\nM.S\n").unwrap().with_holes('.');
+        assert_eq!(CrossSearch::new(holed).count_matches(), 1);
+
+        let blocked = Board::parse("M.S\n.A.\nM.S\n").unwrap().with_holes('A');
+        assert_eq!(CrossSearch::new(blocked).count_matches(), 0);
+    }
+}
@@ -0,0 +1,391 @@
+use std::{cmp::Reverse, fmt, iter};
+
+use common::{Error, Result};
+use fastrand::Rng;
+use grid::{Grid, Point, Vector};
+
+use crate::{
+    board::{Board, Case, Highlight},
+    orientation::{self, Axis, Dedupe, Reading},
+    word_search::{Hit, WordSearchBuilder},
+};
+
+/// Times the generator starts over from an empty grid before giving up
+const ATTEMPTS: usize = 100;
+
+/// Generated word search in which every word occurs exactly once
+pub struct Puzzle<'a> {
+    board: Board,
+    letters: Grid<String>,
+    answers: Vec<Hit<'a>>,
+}
+
+impl<'a> Puzzle<'a> {
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Where each word was placed, in the order the words were given
+    pub fn answers(&self) -> &[Hit<'a>] {
+        &self.answers
+    }
+
+    /// The grid with the cells of every answer highlighted
+    pub fn render_answers(&self, highlight: Highlight) -> String {
+        let mut board = self.board.clone();
+        board.set_matched(self.answers.iter().flat_map(|hit| hit.cells.iter().copied()));
+
+        board.render(highlight)
+    }
+}
+
+/// The grid, an empty line and the answer key, one word per line
+impl fmt::Display for Puzzle<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.letters)?;
+        writeln!(f)?;
+
+        for hit in &self.answers {
+            writeln!(f, "{} at {} towards {}", hit.word, hit.start, hit.direction)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct PuzzleBuilder<'a> {
+    width: usize,
+    height: usize,
+    words: Vec<&'a str>,
+    axes: Vec<Axis>,
+    reading: Reading,
+    alphabet: Option<&'a str>,
+    seed: u64,
+}
+
+impl<'a> PuzzleBuilder<'a> {
+    pub fn new(width: usize, height: usize) -> Self {
+        PuzzleBuilder {
+            width,
+            height,
+            words: Vec::new(),
+            axes: Axis::ALL.to_vec(),
+            reading: Reading::default(),
+            alphabet: None,
+            seed: 0,
+        }
+    }
+
+    /// Add a word to hide, can be called once per word
+    pub fn with_word(mut self, word: &'a str) -> Self {
+        self.words.push(word);
+        self
+    }
+
+    pub fn with_words(mut self, words: impl IntoIterator<Item = &'a str>) -> Self {
+        self.words.extend(words);
+        self
+    }
+
+    /// Only place words along these axes, all four by default
+    pub fn with_axes(mut self, axes: impl IntoIterator<Item = Axis>) -> Self {
+        self.axes = axes.into_iter().collect();
+        self
+    }
+
+    pub fn with_reading(mut self, reading: Reading) -> Self {
+        self.reading = reading;
+        self
+    }
+
+    /// Letters to fill the free cells with, by default the letters of the words
+    pub fn with_alphabet(mut self, alphabet: &'a str) -> Self {
+        self.alphabet = Some(alphabet);
+        self
+    }
+
+    /// The same seed and settings always give the same puzzle
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Place the words, longest first and sharing letters where they cross, then fill every other
+    /// cell with letters that complete no word. A word that contains another one cannot be hidden
+    /// without a second occurrence of the other, so such lists fail
+    pub fn generate(self) -> Result<Puzzle<'a>> {
+        if self.words.is_empty() {
+            return Err(Error::validation("Words to hide not set"));
+        }
+
+        if self.words.contains(&"") {
+            return Err(Error::validation("Words to hide must not be empty"));
+        }
+
+        if let Some((idx, word)) = self.words.iter().enumerate().find(|(idx, word)| self.words[..*idx].contains(word)) {
+            return Err(Error::validation(format!("`{word}` is listed twice, the second time as word {}", idx + 1)));
+        }
+
+        let words: Vec<Vec<String>> = self.words.iter().map(|word| Case::Sensitive.letters(word)).collect();
+        let alphabet = match self.alphabet {
+            Some(alphabet) => distinct(Case::Sensitive.letters(alphabet)),
+            None => distinct(words.concat()),
+        };
+
+        if alphabet.is_empty() {
+            return Err(Error::validation("The alphabet must hold at least one letter"));
+        }
+
+        let directions = orientation::directions(&self.axes, self.reading);
+        let word_directions: Vec<Vec<Vector>> = words
+            .iter()
+            .map(|word| orientation::directions_for(word, &directions, Dedupe::SameCells))
+            .collect();
+
+        let mut order: Vec<usize> = (0..words.len()).collect();
+        order.sort_by_key(|&idx| Reverse(words[idx].len()));
+
+        let mut rng = Rng::with_seed(self.seed);
+
+        for _ in 0..ATTEMPTS {
+            let mut layout = Layout {
+                grid: Grid::filled(self.width, self.height, None),
+                words: &words,
+                word_directions: &word_directions,
+            };
+            let mut placements = vec![None; words.len()];
+
+            for &idx in &order {
+                placements[idx] = layout.place(idx, &directions, &mut rng);
+            }
+
+            if placements.contains(&None) || !layout.fill(&alphabet, &mut rng) {
+                continue;
+            }
+
+            let letters = layout.grid.map(|letter| letter.clone().expect("every cell is filled"));
+            let board = Board::from_letters(&letters);
+            let found = WordSearchBuilder::from_board(board.clone())
+                .with_words(self.words.iter().copied())
+                .with_axes(self.axes.iter().copied())
+                .with_reading(self.reading)
+                .build()?
+                .count_matches();
+
+            // Every word is on the board, so the total only matches when none occurs twice
+            if found as usize != self.words.len() {
+                continue;
+            }
+
+            let answers = self
+                .words
+                .iter()
+                .zip(placements)
+                .map(|(&word, placement)| {
+                    let (start, direction, cells) = placement.expect("every word is placed");
+
+                    Hit {
+                        word,
+                        start,
+                        direction,
+                        cells,
+                    }
+                })
+                .collect();
+
+            return Ok(Puzzle { board, letters, answers });
+        }
+
+        Err(Error::validation(format!(
+            "Could not hide the words in a {}x{} grid after {ATTEMPTS} attempts",
+            self.width, self.height
+        )))
+    }
+}
+
+fn distinct(letters: Vec<String>) -> Vec<String> {
+    let mut distinct: Vec<String> = Vec::new();
+
+    for letter in letters {
+        if !distinct.contains(&letter) {
+            distinct.push(letter);
+        }
+    }
+
+    distinct
+}
+
+/// Grid being generated, `None` for cells that hold no letter yet
+struct Layout<'w> {
+    grid: Grid<Option<String>>,
+    words: &'w [Vec<String>],
+    word_directions: &'w [Vec<Vector>],
+}
+
+impl Layout<'_> {
+    /// Put word `idx` where it only shares cells holding the same letter and forms no other word,
+    /// returning its start, direction and cells
+    fn place(&mut self, idx: usize, directions: &[Vector], rng: &mut Rng) -> Option<(Point, Vector, Vec<Point>)> {
+        let word = &self.words[idx];
+        let mut candidates: Vec<(Point, Vector)> = self
+            .grid
+            .positions()
+            .flat_map(|start| directions.iter().map(move |&direction| (start, direction)))
+            .collect();
+        rng.shuffle(&mut candidates);
+
+        candidates.into_iter().find_map(|(start, direction)| {
+            let cells = self.line(start, direction, word.len())?;
+
+            if !cells.iter().zip(word).all(|(&point, letter)| self.grid[point].as_ref().is_none_or(|placed| placed == letter)) {
+                return None;
+            }
+
+            let new: Vec<Point> = cells.iter().copied().filter(|&point| self.grid[point].is_none()).collect();
+
+            for (&point, letter) in cells.iter().zip(word) {
+                self.grid[point].get_or_insert_with(|| letter.clone());
+            }
+
+            // The word itself, possibly read backwards when it is a palindrome, is the only one allowed
+            let extra = new.iter().any(|&point| {
+                self.occurrences_through(point).into_iter().any(|(other, other_cells)| {
+                    other != idx || !(other_cells.iter().eq(&cells) || other_cells.iter().eq(cells.iter().rev()))
+                })
+            });
+
+            if extra {
+                for point in new {
+                    self.grid[point] = None;
+                }
+
+                return None;
+            }
+
+            Some((start, direction, cells))
+        })
+    }
+
+    /// Fill every free cell with a letter of `alphabet` that completes no word, `false` when some
+    /// cell has no such letter
+    fn fill(&mut self, alphabet: &[String], rng: &mut Rng) -> bool {
+        for point in self.grid.positions() {
+            if self.grid[point].is_some() {
+                continue;
+            }
+
+            let first = rng.usize(..alphabet.len());
+            let fits = alphabet.iter().cycle().skip(first).take(alphabet.len()).any(|letter| {
+                self.grid[point] = Some(letter.clone());
+                self.occurrences_through(point).is_empty()
+            });
+
+            if !fits {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Every word, with its cells, that reads through `cell` in a direction it is counted in
+    fn occurrences_through(&self, cell: Point) -> Vec<(usize, Vec<Point>)> {
+        let mut found = Vec::new();
+
+        for (idx, (word, directions)) in self.words.iter().zip(self.word_directions).enumerate() {
+            for &direction in directions {
+                for offset in 0..word.len() {
+                    let Some(cells) = direction
+                        .checked_mul(-(offset as isize))
+                        .and_then(|back| self.grid.offset(cell, back))
+                        .and_then(|start| self.line(start, direction, word.len()))
+                    else {
+                        continue;
+                    };
+
+                    if cells.iter().zip(word).all(|(&point, letter)| self.grid[point].as_ref() == Some(letter)) {
+                        found.push((idx, cells));
+                    }
+                }
+            }
+        }
+
+        found
+    }
+
+    /// The `length` cells from `start` in `direction`, `None` when they run off the grid
+    fn line(&self, start: Point, direction: Vector, length: usize) -> Option<Vec<Point>> {
+        let cells: Vec<Point> = iter::successors(Some(start), |&point| self.grid.offset(point, direction))
+            .take(length)
+            .collect();
+
+        (cells.len() == length).then_some(cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: [&str; 5] = ["XMAS", "SANTA", "SLEIGH", "ELF", "SNOW"];
+
+    #[test]
+    fn every_word_occurs_once() {
+        for seed in 0..20 {
+            let puzzle = PuzzleBuilder::new(8, 8).with_words(WORDS).with_seed(seed).generate().unwrap();
+            let matches = WordSearchBuilder::from_board(puzzle.board().clone())
+                .with_words(WORDS)
+                .build()
+                .unwrap()
+                .search();
+
+            assert!(matches.counts().all(|(_, count)| count == 1), "seed {seed}:\n{puzzle}");
+
+            for hit in puzzle.answers() {
+                let letters: String = hit
+                    .cells
+                    .iter()
+                    .map(|&point| puzzle.board().get_position(point).unwrap().letter())
+                    .collect();
+
+                assert_eq!(letters, hit.word);
+            }
+        }
+    }
+
+    #[test]
+    fn seeds_and_settings_shape_the_puzzle() {
+        let generate = |seed| PuzzleBuilder::new(6, 6).with_words(WORDS).with_seed(seed).generate().unwrap().to_string();
+
+        assert_eq!(generate(7), generate(7));
+        assert_ne!(generate(7), generate(8));
+
+        let puzzle = PuzzleBuilder::new(7, 5)
+            .with_words(["ΟΔΟΣ", "ΣΠΙΤΙ"])
+            .with_axes([Axis::Horizontal])
+            .with_reading(Reading::Forward)
+            .with_alphabet("ΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠΡΣΤΥΦΧΨΩ")
+            .generate()
+            .unwrap();
+
+        assert!(puzzle.answers().iter().all(|hit| hit.direction == Vector::RIGHT));
+        let mut cells: Vec<Point> = puzzle.answers().iter().flat_map(|hit| hit.cells.clone()).collect();
+        cells.sort_by_key(|point| (point.y, point.x));
+        cells.dedup();
+        let highlighted = puzzle.render_answers(Highlight::Plain).lines().flat_map(str::chars).filter(|&char| char != '.').count();
+
+        assert_eq!(highlighted, cells.len());
+    }
+
+    #[test]
+    fn impossible_word_lists_fail() {
+        let generate = |words: &[&'static str]| PuzzleBuilder::new(5, 5).with_words(words.iter().copied()).generate();
+
+        assert!(generate(&[]).is_err());
+        assert!(generate(&["XMAS", ""]).is_err());
+        assert!(generate(&["XMAS", "XMAS"]).is_err());
+        assert!(generate(&["CHRISTMAS"]).is_err());
+        assert!(generate(&["CAT", "CATS"]).is_err());
+        assert!(generate(&["AB", "BA"]).is_err());
+    }
+}
@@ -0,0 +1,60 @@
+use common::{Example, ParseMode, Result, Solution};
+
+mod board;
+mod byte_board;
+mod cross_search;
+mod generator;
+mod orientation;
+mod pattern;
+mod trie;
+mod word_search;
+
+pub use board::{Board, BoardPosition, Boundary, Case, Highlight};
+pub use cross_search::{CrossSearch, X_MAS};
+pub use generator::{Puzzle, PuzzleBuilder};
+pub use orientation::{Axis, Dedupe, Reading};
+pub use pattern::{Located, Pattern, PatternSet, Symmetry};
+pub use word_search::{Hit, Matches, WordSearch, WordSearchBuilder};
+
+/// Day 4, both parts share the parsed board
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: include_str!("../example.txt"),
+            answer: "18",
+        },
+        Example {
+            part: 2,
+            input: include_str!("../example.txt"),
+            answer: "9",
+        },
+    ];
+
+    type Input = Board;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    /// Any text is valid input, so `mode` has nothing to reject
+    fn parse(input: &str, mode: ParseMode) -> Result<Self::Input> {
+        Board::parse_with(input, mode)
+    }
+
+    /// Number of times `XMAS` appears in the word search
+    fn part1(board: &Self::Input) -> Result<Self::Answer1> {
+        Ok(WordSearchBuilder::from_board(board.clone())
+            .with_word("XMAS")
+            .build()?
+            .count_matches())
+    }
+
+    /// Number of `X-MAS` crosses in the word search
+    fn part2(board: &Self::Input) -> Result<Self::Answer2> {
+        Ok(CrossSearch::new(board.clone()).count_matches())
+    }
+}
+
+common::example_tests!(Day04);
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day04::Day04;
+
+fn main() -> ExitCode {
+    common::cli::run::<Day04>("./puzzles/day04/input.txt")
+}
@@ -0,0 +1,110 @@
+use grid::Vector;
+
+/// Line through the board a word can lie along
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+    /// From the top left towards the bottom right
+    Diagonal,
+    /// From the bottom left towards the top right
+    AntiDiagonal,
+}
+
+impl Axis {
+    pub const ALL: [Axis; 4] = [Axis::Horizontal, Axis::Vertical, Axis::Diagonal, Axis::AntiDiagonal];
+
+    /// Direction of a word read forwards along the axis
+    pub fn forward(self) -> Vector {
+        match self {
+            Axis::Horizontal => Vector::RIGHT,
+            Axis::Vertical => Vector::DOWN,
+            Axis::Diagonal => Vector::new(1, 1),
+            Axis::AntiDiagonal => Vector::new(1, -1),
+        }
+    }
+}
+
+/// Which ways along its axis a word may read
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Reading {
+    /// Left to right, top to bottom, and up or down the diagonals towards the right
+    Forward,
+    #[default]
+    Both,
+}
+
+/// When two hits of the same word are counted as one
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Dedupe {
+    /// Hits covering the same cells count once, so a palindrome read both ways is a single occurrence
+    #[default]
+    SameCells,
+    /// Every start and direction counts on its own
+    Never,
+}
+
+/// Directions to try from every cell, given the axes and the way words may read
+pub(crate) fn directions(axes: &[Axis], reading: Reading) -> Vec<Vector> {
+    let forward = axes.iter().map(|axis| axis.forward());
+
+    match reading {
+        Reading::Forward => forward.collect(),
+        Reading::Both => forward.clone().chain(forward.map(Vector::reversed)).collect(),
+    }
+}
+
+/// The subset of `directions` in which the letters of `word` are counted, dropping those that
+/// would only find the cells of another direction again
+pub(crate) fn directions_for(word: &[String], directions: &[Vector], dedupe: Dedupe) -> Vec<Vector> {
+    if dedupe == Dedupe::Never {
+        return directions.to_vec();
+    }
+
+    // A single letter covers the same cell whichever way it is read
+    if word.len() < 2 {
+        return directions.iter().copied().take(1).collect();
+    }
+
+    // A palindrome read backwards covers the cells of the forward reading
+    if word.iter().eq(word.iter().rev()) {
+        let forward: Vec<Vector> = Axis::ALL.iter().map(|axis| axis.forward()).collect();
+
+        return directions
+            .iter()
+            .copied()
+            .filter(|direction| forward.contains(direction) || !directions.contains(&direction.reversed()))
+            .collect();
+    }
+
+    directions.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Case;
+
+    #[test]
+    fn readings_choose_directions() {
+        assert_eq!(directions(&Axis::ALL, Reading::Both).len(), 8);
+        assert_eq!(directions(&[Axis::Horizontal], Reading::Forward), [Vector::RIGHT]);
+        assert_eq!(directions(&[Axis::Vertical], Reading::Both), [Vector::DOWN, Vector::UP]);
+    }
+
+    #[test]
+    fn palindromes_keep_one_direction_per_axis() {
+        let all = directions(&Axis::ALL, Reading::Both);
+        let directions_for = |word, directions: &[Vector], dedupe| {
+            directions_for(&Case::Insensitive.letters(word), directions, dedupe)
+        };
+
+        assert_eq!(directions_for("XMAS", &all, Dedupe::SameCells).len(), 8);
+        assert_eq!(directions_for("ABA", &all, Dedupe::SameCells).len(), 4);
+        assert_eq!(directions_for("ABA", &all, Dedupe::Never).len(), 8);
+        assert_eq!(directions_for("A", &all, Dedupe::SameCells).len(), 1);
+        assert_eq!(directions_for("Aba", &all, Dedupe::SameCells).len(), 4);
+        assert_eq!(directions_for("e\u{301}", &all, Dedupe::SameCells).len(), 1);
+        assert_eq!(directions_for("ABA", &[Vector::LEFT], Dedupe::SameCells), [Vector::LEFT]);
+    }
+}
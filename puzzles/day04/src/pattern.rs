@@ -0,0 +1,223 @@
+use std::fmt;
+
+use common::{Error, Result};
+use grid::{Grid, Point, Vector};
+
+use crate::board::Board;
+
+/// Small grid of letters to find on a board, `.` matching any letter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    grid: Grid<Option<Box<str>>>,
+}
+
+/// Which transformed copies of a pattern are searched for as well
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Symmetry {
+    /// Only the pattern as written
+    #[default]
+    None,
+    /// The pattern turned by every quarter turn
+    Rotations,
+    /// Every rotation of the pattern and of its mirror image
+    RotationsAndReflections,
+}
+
+impl Pattern {
+    /// Parse rows separated by `/` or newlines, such as `M.S/.A./M.S`, one cell per grapheme cluster
+    pub fn parse(pattern: &str) -> Result<Self> {
+        let grid = Grid::parse_graphemes(&pattern.replace('/', "\n"), |letter| (letter != ".").then(|| letter.into()))?;
+
+        if grid.width() == 0 || grid.height() == 0 {
+            return Err(Error::validation("A pattern needs at least one cell"));
+        }
+
+        Ok(Pattern { grid })
+    }
+
+    /// The pattern turned a quarter clockwise
+    pub fn rotated(&self) -> Self {
+        let (width, height) = (self.grid.height(), self.grid.width());
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(y, self.grid.height() - 1 - x)))
+            .map(|source| self.grid[source].clone())
+            .collect();
+
+        Pattern {
+            grid: Grid::new(width, height, cells).expect("rotation keeps the number of cells"),
+        }
+    }
+
+    /// The pattern flipped left to right
+    pub fn mirrored(&self) -> Self {
+        let width = self.grid.width();
+        let cells = self
+            .grid
+            .positions()
+            .map(|point| self.grid[Point::new(width - 1 - point.x, point.y)].clone())
+            .collect();
+
+        Pattern {
+            grid: Grid::new(width, self.grid.height(), cells).expect("mirroring keeps the number of cells"),
+        }
+    }
+
+    /// The pattern together with its distinct transformed copies
+    pub fn with_symmetry(self, symmetry: Symmetry) -> PatternSet {
+        let turns = |pattern: Pattern| {
+            let mut turns = vec![pattern];
+
+            for _ in 0..3 {
+                turns.push(turns[turns.len() - 1].rotated());
+            }
+
+            turns
+        };
+
+        let patterns = match symmetry {
+            Symmetry::None => vec![self],
+            Symmetry::Rotations => turns(self),
+            Symmetry::RotationsAndReflections => {
+                let mirrored = self.mirrored();
+                let mut patterns = turns(self);
+                patterns.extend(turns(mirrored));
+                patterns
+            }
+        };
+
+        PatternSet::new(patterns)
+    }
+
+    /// Whether the pattern lies on `board` with its top left cell at `origin`. Wildcards only need
+    /// to be on the board, they may cover blocked cells
+    pub fn matches_at(&self, board: &Board, origin: Point) -> bool {
+        self.grid.iter().all(|(offset, cell)| {
+            let Some(point) = board.step(origin, Vector::new(offset.x as isize, offset.y as isize)) else {
+                return false;
+            };
+
+            cell.as_deref().is_none_or(|letter| {
+                board
+                    .get_position(point)
+                    .is_some_and(|position| position.matches(&board.case().fold(letter)))
+            })
+        })
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                write!(f, "/")?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell.as_deref().unwrap_or("."))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Patterns searched for together, each counted where it matches
+#[derive(Clone, Debug)]
+pub struct PatternSet {
+    patterns: Vec<Pattern>,
+}
+
+/// Place on the board where one of the patterns of a set matched
+#[derive(Clone, Copy, Debug)]
+pub struct Located<'p> {
+    /// Board position of the top left cell of the pattern
+    pub origin: Point,
+    pub pattern: &'p Pattern,
+}
+
+impl PatternSet {
+    /// Set of the given patterns, dropping duplicates
+    pub fn new(patterns: impl IntoIterator<Item = Pattern>) -> Self {
+        let mut distinct: Vec<Pattern> = Vec::new();
+
+        for pattern in patterns {
+            if !distinct.contains(&pattern) {
+                distinct.push(pattern);
+            }
+        }
+
+        PatternSet { patterns: distinct }
+    }
+
+    pub fn patterns(&self) -> &[Pattern] {
+        &self.patterns
+    }
+
+    /// Every place a pattern matches, in board order
+    pub fn locate<'p>(&'p self, board: &Board) -> Vec<Located<'p>> {
+        let mut located = Vec::new();
+
+        for y in 0..board.height() {
+            for x in 0..board.width() {
+                let origin = Point::new(x, y);
+
+                located.extend(
+                    self.patterns
+                        .iter()
+                        .filter(|pattern| pattern.matches_at(board, origin))
+                        .map(|pattern| Located { origin, pattern }),
+                );
+            }
+        }
+
+        located
+    }
+
+    pub fn count(&self, board: &Board) -> usize {
+        self.locate(board).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(set: &PatternSet) -> Vec<String> {
+        set.patterns().iter().map(Pattern::to_string).collect()
+    }
+
+    #[test]
+    fn transforms_patterns() {
+        let pattern = Pattern::parse("AB./..C").unwrap();
+
+        assert_eq!(pattern.rotated().to_string(), ".A/.B/C.");
+        assert_eq!(pattern.mirrored().to_string(), ".BA/C..");
+        assert_eq!(pattern.rotated().rotated().rotated().rotated(), pattern);
+    }
+
+    #[test]
+    fn variants_are_deduplicated() {
+        let x_mas = Pattern::parse("M.S/.A./M.S").unwrap();
+
+        assert_eq!(
+            strings(&x_mas.clone().with_symmetry(Symmetry::RotationsAndReflections)),
+            ["M.S/.A./M.S", "M.M/.A./S.S", "S.M/.A./S.M", "S.S/.A./M.M"]
+        );
+        assert_eq!(x_mas.with_symmetry(Symmetry::None).patterns().len(), 1);
+        assert_eq!(Pattern::parse("A").unwrap().with_symmetry(Symmetry::Rotations).patterns().len(), 1);
+    }
+
+    #[test]
+    fn locates_patterns_on_a_board() {
+        let board = Board::parse("XAB\nABY\n").unwrap();
+        let set = Pattern::parse("AB").unwrap().with_symmetry(Symmetry::None);
+
+        let origins: Vec<_> = set.locate(&board).iter().map(|located| located.origin).collect();
+
+        assert_eq!(origins, [Point::new(1, 0), Point::new(0, 1)]);
+        assert!(Pattern::parse("").is_err());
+
+        let folded = Board::parse("ÉA\n").unwrap().with_case(crate::board::Case::Insensitive);
+        assert_eq!(Pattern::parse("é.").unwrap().with_symmetry(Symmetry::None).count(&folded), 1);
+    }
+}
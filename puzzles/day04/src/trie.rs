@@ -0,0 +1,92 @@
+/// Prefix tree over the letters of a set of words, walked one board cell at a time
+#[derive(Clone, Debug)]
+pub(crate) struct Trie {
+    nodes: Vec<Node>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    /// Sorted by letter so a step is a binary search
+    children: Vec<(Box<str>, usize)>,
+    /// Index of the word ending here, the first one if a word was given twice
+    word: Option<usize>,
+}
+
+impl Trie {
+    pub(crate) const ROOT: usize = 0;
+
+    /// Trie of words given as their letters, see [`crate::Case::letters`]
+    pub(crate) fn new<'w>(words: impl IntoIterator<Item = &'w [String]>) -> Self {
+        let mut trie = Trie {
+            nodes: vec![Node::default()],
+        };
+
+        for (idx, word) in words.into_iter().enumerate() {
+            trie.insert(word, idx);
+        }
+
+        trie
+    }
+
+    fn insert(&mut self, word: &[String], idx: usize) {
+        let mut node = Trie::ROOT;
+
+        for letter in word {
+            node = match self.nodes[node].children.binary_search_by_key(&letter.as_str(), |(child, _)| child) {
+                Ok(child) => self.nodes[node].children[child].1,
+                Err(insert_at) => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].children.insert(insert_at, (letter.as_str().into(), child));
+                    child
+                }
+            };
+        }
+
+        self.nodes[node].word.get_or_insert(idx);
+    }
+
+    /// Node reached from `node` by reading `letter`, `None` when no word continues that way
+    pub(crate) fn step(&self, node: usize, letter: &str) -> Option<usize> {
+        let children = &self.nodes[node].children;
+        let child = children.binary_search_by_key(&letter, |(child, _)| child).ok()?;
+
+        Some(children[child].1)
+    }
+
+    /// Index of the word that ends at `node`
+    pub(crate) fn word(&self, node: usize) -> Option<usize> {
+        self.nodes[node].word
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Case;
+
+    fn walk(trie: &Trie, text: &str) -> Vec<usize> {
+        let mut node = Trie::ROOT;
+        let mut words = Vec::new();
+
+        for letter in Case::Sensitive.letters(text) {
+            let Some(next) = trie.step(node, &letter) else {
+                break;
+            };
+            node = next;
+            words.extend(trie.word(node));
+        }
+
+        words
+    }
+
+    #[test]
+    fn finds_every_word_along_a_walk() {
+        let words = ["XMAS", "XM", "SAMX", "XM"].map(|word| Case::Sensitive.letters(word));
+        let trie = Trie::new(words.iter().map(Vec::as_slice));
+
+        assert_eq!(walk(&trie, "XMASX"), [1, 0]);
+        assert_eq!(walk(&trie, "SAMXMAS"), [2]);
+        assert!(walk(&trie, "MAS").is_empty());
+    }
+}
@@ -0,0 +1,497 @@
+use std::iter;
+
+use common::{Error, Result};
+use grid::{Point, Vector};
+
+use crate::{
+    board::{Board, Case, Highlight},
+    byte_board::ByteBoard,
+    orientation::{self, Axis, Dedupe, Reading},
+    trie::Trie,
+};
+
+pub struct WordSearch<'a> {
+    board: Board,
+    words: Vec<&'a str>,
+    /// Letters of each word, folded with the case of the board
+    letters: Vec<Vec<String>>,
+    trie: Trie,
+    /// Every direction tried from each cell
+    directions: Vec<Vector>,
+    /// Directions in which each word is counted, after deduplication
+    word_directions: Vec<Vec<Vector>>,
+    /// Present when the board is ASCII, which allows the fast path of [`WordSearch::count_matches`]
+    bytes: Option<ByteBoard>,
+}
+
+/// Occurrence of a word, reading from `start` in `direction`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hit<'a> {
+    pub word: &'a str,
+    pub start: Point,
+    pub direction: Vector,
+    /// Cells covered by the word from its first letter to its last, which may cross an edge of a
+    /// wrapping or mirrored board
+    pub cells: Vec<Point>,
+}
+
+/// Everything a dictionary search found
+#[derive(Debug)]
+pub struct Matches<'a> {
+    words: Vec<&'a str>,
+    counts: Vec<u32>,
+    hits: Vec<Hit<'a>>,
+}
+
+impl<'a> Matches<'a> {
+    /// Number of occurrences of `word`, zero for a word that was not searched for
+    pub fn count(&self, word: &str) -> u32 {
+        self.words
+            .iter()
+            .position(|&searched| searched == word)
+            .map_or(0, |idx| self.counts[idx])
+    }
+
+    /// Every searched word with its number of occurrences, in the order they were given
+    pub fn counts(&self) -> impl Iterator<Item = (&'a str, u32)> {
+        self.words.iter().copied().zip(self.counts.iter().copied())
+    }
+
+    pub fn total(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// Hits in board order, then by direction
+    pub fn hits(&self) -> &[Hit<'a>] {
+        &self.hits
+    }
+}
+
+impl<'a> WordSearch<'a> {
+    /// Total number of occurrences of the words, checking each word on its own
+    pub fn count_matches(&mut self) -> u32 {
+        let Some(bytes) = &self.bytes else {
+            return self.count_matches_by_char();
+        };
+        let mut count = 0;
+
+        for (letters, directions) in self.letters.iter().zip(&self.word_directions) {
+            let word = letters.concat();
+
+            // A word outside ASCII cannot be on an ASCII board, nor can one with a `\r\n` letter
+            if !word.is_ascii() || word.len() != letters.len() {
+                continue;
+            }
+            let length = word.len();
+
+            count += bytes.count(word.as_bytes(), directions, |start, direction| {
+                let cells = iter::successors(Some(start), |point| point.checked_add(direction));
+                self.board.set_matched(cells.take(length));
+            });
+        }
+
+        count
+    }
+
+    /// [`WordSearch::count_matches`] comparing letters cell by cell, as done for boards that are not
+    /// ASCII. Public only so the benchmark can compare it with the byte path
+    #[doc(hidden)]
+    pub fn count_matches_by_char(&mut self) -> u32 {
+        let WordSearch {
+            board,
+            letters,
+            word_directions,
+            ..
+        } = self;
+        let mut count = 0;
+
+        for (word, directions) in letters.iter().zip(word_directions.iter()) {
+            for y in 0..board.height() {
+                for x in 0..board.width() {
+                    let start = Point::new(x, y);
+
+                    for &direction in directions {
+                        if reads_word(board, word, start, direction) {
+                            board.mark_walk(start, direction, word.len());
+                            count += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Find every word in a single pass over the board, walking the trie of all words from each cell
+    /// so the cost depends on the length of the words rather than how many there are
+    pub fn search(&self) -> Matches<'a> {
+        let mut matches = Matches {
+            words: self.words.clone(),
+            counts: vec![0; self.words.len()],
+            hits: Vec::new(),
+        };
+        let mut path = Vec::new();
+
+        for y in 0..self.board.height() {
+            for x in 0..self.board.width() {
+                let start = Point::new(x, y);
+
+                for &direction in &self.directions {
+                    let mut node = Trie::ROOT;
+                    path.clear();
+
+                    for point in self.board.walk(start, direction) {
+                        let Some(next) = self
+                            .board
+                            .get_position(point)
+                            .and_then(|board_position| self.trie.step(node, board_position.key()))
+                        else {
+                            break;
+                        };
+
+                        node = next;
+                        path.push(point);
+
+                        if let Some(word) = self.trie.word(node)
+                            && self.word_directions[word].contains(&direction)
+                        {
+                            matches.counts[word] += 1;
+                            matches.hits.push(Hit {
+                                word: self.words[word],
+                                start,
+                                direction,
+                                cells: path.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        matches
+    }
+
+    /// Like [`WordSearch::search`], also marking the covered cells for [`WordSearch::render`]
+    pub fn find_matches(&mut self) -> Matches<'a> {
+        let matches = self.search();
+
+        for hit in matches.hits() {
+            self.board.set_matched(hit.cells.iter().copied());
+        }
+
+        matches
+    }
+
+    /// The board with every cell covered by a match found so far highlighted
+    pub fn render(&self, highlight: Highlight) -> String {
+        self.board.render(highlight)
+    }
+}
+
+/// Whether `word` reads from `start` in `direction`, one letter per cell
+fn reads_word(board: &Board, word: &[String], start: Point, direction: Vector) -> bool {
+    let mut cells = board.walk(start, direction);
+
+    word.iter().all(|letter| {
+        cells
+            .next()
+            .and_then(|point| board.get_position(point))
+            .is_some_and(|position| position.matches(letter))
+    })
+}
+
+pub struct WordSearchBuilder<'a> {
+    board: Option<Board>,
+    words: Vec<&'a str>,
+    axes: Vec<Axis>,
+    reading: Reading,
+    dedupe: Dedupe,
+    /// Overrides the case of the board when set
+    case: Option<Case>,
+}
+
+impl<'a> WordSearchBuilder<'a> {
+    pub fn parse(input: &str) -> Result<Self> {
+        let board = Board::parse(input)?;
+
+        Ok(WordSearchBuilder::from_board(board))
+    }
+
+    pub fn from_board(board: Board) -> Self {
+        WordSearchBuilder {
+            board: Some(board),
+            words: Vec::new(),
+            axes: Axis::ALL.to_vec(),
+            reading: Reading::default(),
+            dedupe: Dedupe::default(),
+            case: None,
+        }
+    }
+
+    pub fn from_vecs(board: Vec<Vec<char>>) -> Result<Self> {
+        Ok(WordSearchBuilder::from_board(Board::from_vecs(board)?))
+    }
+
+    /// Add a word to search for, can be called once per word
+    pub fn with_word(mut self, word: &'a str) -> Self {
+        self.words.push(word);
+        self
+    }
+
+    pub fn with_words(mut self, words: impl IntoIterator<Item = &'a str>) -> Self {
+        self.words.extend(words);
+        self
+    }
+
+    /// Only look for words along these axes, all four by default
+    pub fn with_axes(mut self, axes: impl IntoIterator<Item = Axis>) -> Self {
+        self.axes = axes.into_iter().collect();
+        self
+    }
+
+    pub fn with_reading(mut self, reading: Reading) -> Self {
+        self.reading = reading;
+        self
+    }
+
+    pub fn with_dedupe(mut self, dedupe: Dedupe) -> Self {
+        self.dedupe = dedupe;
+        self
+    }
+
+    /// Match letters regardless of case, see [`Board::with_case`]
+    pub fn with_case(mut self, case: Case) -> Self {
+        self.case = Some(case);
+        self
+    }
+
+    pub fn build(self) -> Result<WordSearch<'a>> {
+        let mut board = self.board.ok_or_else(|| Error::validation("Board not set"))?;
+
+        if let Some(case) = self.case {
+            board = board.with_case(case);
+        }
+
+        if self.words.is_empty() {
+            return Err(Error::validation("Word to match not set"));
+        }
+
+        if self.words.contains(&"") {
+            return Err(Error::validation("Words to match must not be empty"));
+        }
+
+        let letters: Vec<Vec<String>> = self.words.iter().map(|word| board.case().letters(word)).collect();
+        let directions = orientation::directions(&self.axes, self.reading);
+        let word_directions = letters
+            .iter()
+            .map(|word| orientation::directions_for(word, &directions, self.dedupe))
+            .collect();
+
+        Ok(WordSearch {
+            bytes: ByteBoard::new(&board),
+            board,
+            trie: Trie::new(letters.iter().map(Vec::as_slice)),
+            words: self.words,
+            letters,
+            directions,
+            word_directions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Boundary;
+
+    /// Number of matches, checking that the byte, letter and trie paths all agree on it
+    fn count_all_paths(search: impl Fn() -> WordSearch<'static>) -> u32 {
+        let count = search().count_matches();
+
+        assert_eq!(search().count_matches_by_char(), count);
+        assert_eq!(search().search().total(), count);
+        count
+    }
+
+    #[test]
+    fn test_word_search() {
+        let board: Vec<Vec<char>> = vec![
+            vec!['M', 'M', 'M', 'S', 'X', 'X', 'M', 'A', 'S', 'M'],
+            vec!['M', 'S', 'A', 'M', 'X', 'M', 'S', 'M', 'S', 'A'],
+            vec!['A', 'M', 'X', 'S', 'X', 'M', 'A', 'A', 'M', 'M'],
+            vec!['M', 'S', 'A', 'M', 'A', 'S', 'M', 'S', 'M', 'X'],
+            vec!['X', 'M', 'A', 'S', 'A', 'M', 'X', 'A', 'M', 'M'],
+            vec!['X', 'X', 'A', 'M', 'M', 'X', 'X', 'A', 'M', 'A'],
+            vec!['S', 'M', 'S', 'M', 'S', 'A', 'S', 'X', 'S', 'S'],
+            vec!['S', 'A', 'X', 'A', 'M', 'A', 'S', 'A', 'A', 'A'],
+            vec!['M', 'A', 'M', 'M', 'M', 'X', 'M', 'M', 'M', 'M'],
+            vec!['M', 'X', 'M', 'X', 'A', 'X', 'M', 'A', 'S', 'X']
+        ];
+        
+        let word_search = WordSearchBuilder::from_vecs(board)
+            .unwrap()
+            .with_word("XMAS");
+
+        let count = word_search.build().unwrap().count_matches();
+
+        assert_eq!(count, 18);
+    }
+
+    #[test]
+    fn test_word_search_at_the_edges() {
+        for row in ["XMAS", "SAMX"] {
+            let word_search = WordSearchBuilder::from_vecs(vec![row.chars().collect()])
+                .unwrap()
+                .with_word("XMAS");
+
+            assert_eq!(word_search.build().unwrap().count_matches(), 1, "{row}");
+        }
+    }
+
+    #[test]
+    fn test_search_dictionary() {
+        let words = ["XMAS", "SAMX", "MAS", "AM", "ZZZ"];
+        let word_search = WordSearchBuilder::parse(include_str!("../example.txt"))
+            .unwrap()
+            .with_words(words)
+            .build()
+            .unwrap();
+
+        let matches = word_search.search();
+
+        for word in words {
+            let mut single = WordSearchBuilder::parse(include_str!("../example.txt"))
+                .unwrap()
+                .with_word(word)
+                .build()
+                .unwrap();
+
+            assert_eq!(matches.count(word), single.count_matches(), "{word}");
+        }
+
+        assert_eq!(matches.count("XMAS"), 18);
+        assert_eq!(matches.count("ZZZ"), 0);
+        assert_eq!(matches.hits().len() as u32, matches.total());
+        assert!(matches.hits().contains(&Hit {
+            word: "XMAS",
+            start: Point::new(5, 0),
+            direction: Vector::RIGHT,
+            cells: (5..9).map(|x| Point::new(x, 0)).collect(),
+        }));
+    }
+
+    #[test]
+    fn test_render_matches() {
+        let mut word_search = WordSearchBuilder::parse(include_str!("../example.txt"))
+            .unwrap()
+            .with_word("XMAS")
+            .build()
+            .unwrap();
+
+        let matches = word_search.find_matches();
+        let hit = matches.hits().iter().find(|hit| hit.start == Point::new(9, 3)).unwrap();
+
+        assert_eq!(hit.direction, Vector::DOWN);
+        assert_eq!(hit.cells, (3..7).map(|y| Point::new(9, y)).collect::<Vec<_>>());
+        assert_eq!(
+            word_search.render(Highlight::Plain),
+            "....XXMAS.\n\
+             .SAMXMS...\n\
+             ...S..A...\n\
+             ..A.A.MS.X\n\
+             XMASAMX.MM\n\
+             X.....XA.A\n\
+             S.S.S.S.SS\n\
+             .A.A.A.A.A\n\
+             ..M.M.M.MM\n\
+             .X.X.XMASX"
+        );
+    }
+
+    #[test]
+    fn test_render_ansi() {
+        let mut word_search = WordSearchBuilder::from_vecs(vec!["XMASX".chars().collect()])
+            .unwrap()
+            .with_word("XMAS")
+            .build()
+            .unwrap();
+
+        word_search.find_matches();
+
+        assert_eq!(
+            word_search.render(Highlight::Ansi),
+            "\x1b[1;32mX\x1b[0m\x1b[1;32mM\x1b[0m\x1b[1;32mA\x1b[0m\x1b[1;32mS\x1b[0m\x1b[2mX\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_byte_and_char_paths_agree() {
+        let boards = [include_str!("../example.txt"), "ABA\nBAB\nABA\n", "ÄBA\nBAB\nABA\n"];
+
+        for board in boards {
+            for word in ["XMAS", "A", "ABA", "BAB", "ÄB"] {
+                let build = || WordSearchBuilder::parse(board).unwrap().with_word(word).build().unwrap();
+
+                let (mut bytes, mut chars) = (build(), build());
+
+                assert_eq!(bytes.count_matches(), chars.count_matches_by_char(), "{word} on {board}");
+                assert_eq!(bytes.render(Highlight::Plain), chars.render(Highlight::Plain), "{word} on {board}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_orientation_and_palindromes() {
+        let build = |builder: WordSearchBuilder<'static>| builder.with_word("ABA").build().unwrap();
+        let board = || WordSearchBuilder::parse("ABA\nBBB\nABA\n").unwrap();
+
+        assert_eq!(build(board()).count_matches(), 6);
+        assert_eq!(build(board()).count_matches_by_char(), 6);
+        assert_eq!(build(board()).search().count("ABA"), 6);
+        assert_eq!(build(board().with_dedupe(Dedupe::Never)).count_matches(), 12);
+        assert_eq!(build(board().with_axes([Axis::Horizontal])).count_matches(), 2);
+
+        let mut forward = WordSearchBuilder::parse("SAMX\n")
+            .unwrap()
+            .with_word("XMAS")
+            .with_reading(Reading::Forward)
+            .build()
+            .unwrap();
+        assert_eq!(forward.count_matches(), 0);
+        assert_eq!(forward.count_matches_by_char(), 0);
+    }
+
+    #[test]
+    fn test_boundaries_and_holes() {
+        let count = |board: Board| {
+            count_all_paths(|| WordSearchBuilder::from_board(board.clone()).with_word("XMAS").build().unwrap())
+        };
+        let board = Board::parse("ASXM\n....\n").unwrap();
+
+        assert_eq!(count(board.clone()), 0);
+        assert_eq!(count(board.with_boundary(Boundary::Wrap)), 1);
+        assert_eq!(count(Board::parse("XMA\n...\n").unwrap().with_boundary(Boundary::Mirror)), 0);
+        assert_eq!(count(Board::parse("..XMA\n.....\n").unwrap().with_boundary(Boundary::Mirror)), 0);
+        assert_eq!(count(Board::parse("SAMX\n....\n").unwrap().with_boundary(Boundary::Mirror)), 1);
+        assert_eq!(count(Board::parse("XM#AS\n").unwrap().with_holes('#')), 0);
+        assert_eq!(count(Board::parse("XMAS#\n").unwrap().with_holes('#')), 1);
+    }
+
+    #[test]
+    fn test_graphemes_and_case() {
+        let count = |board: &str, word: &'static str, case: Case| {
+            count_all_paths(|| WordSearchBuilder::parse(board).unwrap().with_word(word).with_case(case).build().unwrap())
+        };
+        // `CAFÉ` down the first column, its `É` written as `E` and a combining accent
+        let board = "Cxy\nAxy\nFxy\nE\u{301}xy\n";
+
+        assert_eq!(count(board, "CAFE\u{301}", Case::Sensitive), 1);
+        assert_eq!(count(board, "CAFE", Case::Sensitive), 0);
+        assert_eq!(count(board, "café", Case::Sensitive), 0);
+        assert_eq!(count(board, "cafe\u{301}", Case::Insensitive), 1);
+        assert_eq!(count("ΟΔΟΣ\nxxxx\n", "οδος", Case::Insensitive), 1);
+        assert_eq!(count("xmas\nSaMx\n", "XMAS", Case::Insensitive), 2);
+        assert_eq!(count("xmas\nSaMx\n", "XMAS", Case::Sensitive), 0);
+    }
+}
@@ -0,0 +1,332 @@
+use std::{
+    collections::{HashMap, HashSet}
+};
+
+use common::{Example, ParseMode, Result, Solution, error::parse_at};
+use regex::Regex;
+
+/// Day 5, both parts share the parsed rules and updates
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: include_str!("../example.txt"),
+            answer: "143",
+        },
+        Example {
+            part: 2,
+            input: include_str!("../example.txt"),
+            answer: "123",
+        },
+    ];
+
+    type Input = PrintQueue;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str, mode: ParseMode) -> Result<Self::Input> {
+        Ok(PrintQueue {
+            page_order: PageOrderBuilder::parse(input)?.build(),
+            sequences: read_sequences(input, mode)?,
+        })
+    }
+
+    /// Sum of the middle pages of all updates that are already in order
+    fn part1(queue: &Self::Input) -> Result<Self::Answer1> {
+        let page_order = &queue.page_order;
+
+        Ok(queue.sequences.iter()
+            .filter(|sequence| page_order.is_in_order(sequence))
+            .map(|sequence| page_order.get_middle_value(sequence))
+            .sum())
+    }
+
+    /// Sum of the middle pages of all out-of-order updates once they are put in order
+    fn part2(queue: &Self::Input) -> Result<Self::Answer2> {
+        let page_order = &queue.page_order;
+
+        Ok(queue.sequences.iter()
+            .filter(|sequence| !page_order.is_in_order(sequence))
+            .map(|sequence| page_order.put_in_order(sequence))
+            .map(|sequence| page_order.get_middle_value(&sequence))
+            .sum())
+    }
+}
+
+common::example_tests!(Day05);
+
+/// Page ordering rules and the updates to check against them
+pub struct PrintQueue {
+    pub page_order: PageOrder,
+    pub sequences: Vec<Vec<isize>>,
+}
+
+pub fn read_sequences(input: &str, mode: ParseMode) -> Result<Vec<Vec<isize>>> {
+    let match_regex = Regex::new(r"\d+").expect("pattern is valid");
+    let forbidden_regex = Regex::new(r"\d+\|\d+").expect("pattern is valid");
+    let rule_regex = Regex::new(r"^\d+\|\d+$").expect("pattern is valid");
+    let sequence_regex = Regex::new(r"^\d+(?:,\d+)*$").expect("pattern is valid");
+
+    let mut sequences = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        if !rule_regex.is_match(line) && !sequence_regex.is_match(line) {
+            mode.reject(idx, line, "a rule like `47|53` or an update like `75,47,61`")?;
+            continue;
+        }
+
+        if forbidden_regex.is_match(line) {
+            continue;
+        }
+
+        if line.is_empty() {
+            continue;
+        }
+
+        sequences.push(
+            match_regex.find_iter(line)
+                .map(|page| parse_at::<isize>(idx, line, page.range()))
+                .collect::<Result<Vec<isize>>>()?
+        )
+    }
+    
+    Ok(sequences)
+}
+
+pub struct PageOrder {
+    succeeding_pages: HashMap<isize, HashSet<isize>>,
+    preceeding_pages: HashMap<isize, HashSet<isize>>
+}
+
+impl PageOrder {
+    pub fn is_in_order(&self, update: &[isize]) -> bool {
+        for (x_idx, x) in update.iter().enumerate() {
+            for (y_idx, y) in update.iter().enumerate() {
+                if x_idx == y_idx {
+                    continue;
+                }
+
+                if x_idx < y_idx
+                    && let Some(set) = self.succeeding_pages.get(x)
+                    && !set.contains(y)
+                {
+                    return false;
+                }
+
+                if x_idx > y_idx
+                    && let Some(set) = self.preceeding_pages.get(x)
+                    && !set.contains(y)
+                {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    pub fn get_middle_value(&self, update: &[isize]) -> isize {
+        let middle = update.len() / 2;
+        update[middle]
+    }
+
+
+    pub fn put_in_order(&self, update: &[isize]) -> Vec<isize> {
+        let mut ordered = update.to_vec();
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+            let len = ordered.len();
+
+            for x_idx in 0..len {
+
+                let x = ordered[x_idx];
+
+                for y_idx in 0..len {
+                    if x_idx == y_idx {
+                        continue;
+                    }
+                    
+                    let y = ordered[y_idx];
+
+                    if x_idx < y_idx
+                        && let Some(succeeding_pages) = self.succeeding_pages.get(&x)
+                        && !succeeding_pages.contains(&y)
+                        && let Some(preceeding_pages) = self.preceeding_pages.get(&x)
+                        && preceeding_pages.contains(&y)
+                    {
+                        changed = true;
+                        ordered.swap(x_idx, y_idx);
+                    }
+
+                    if x_idx > y_idx
+                        && let Some(preceeding_pages) = self.preceeding_pages.get(&x)
+                        && !preceeding_pages.contains(&y)
+                        && let Some(succeeding_pages) = self.succeeding_pages.get(&x)
+                        && succeeding_pages.contains(&y)
+                    {
+                        changed = true;
+                        ordered.swap(x_idx, y_idx);
+                    }
+                }
+            }
+        }
+
+        ordered
+    }
+}
+
+#[derive(Default)]
+pub struct PageOrderBuilder {
+    rules: Vec<(isize, isize)>
+}
+
+impl PageOrderBuilder {
+    pub fn parse(input: &str) -> Result<Self> {
+        let regex = Regex::new(r"^(\d+)\|(\d+)$").expect("pattern is valid");
+
+        let mut page_order_builder = PageOrderBuilder::new();
+
+        for (idx, line) in input.lines().enumerate() {
+            if let Some(captures) = regex.captures(line) {
+                let a = parse_at::<isize>(idx, line, captures.get(1).expect("group 1").range())?;
+                let b = parse_at::<isize>(idx, line, captures.get(2).expect("group 2").range())?;
+
+                page_order_builder = page_order_builder.add_rule(a, b);
+            }
+        }
+
+        Ok(page_order_builder)
+    }
+
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new()
+        }
+    }
+
+    pub fn add_rule(mut self, a: isize, b: isize) -> Self {
+        self.rules.push((a, b));
+        self
+    }
+
+    pub fn build(self) -> PageOrder {
+        let mut succeeding_pages: HashMap<isize, HashSet<isize>> = HashMap::new();
+        let mut preceeding_pages: HashMap<isize, HashSet<isize>> = HashMap::new();
+
+        for (left, right) in self.rules {
+            if let Some(set) = succeeding_pages.get_mut(&left) {
+                set.insert(right);
+            } else {
+                let mut new_set = HashSet::new();
+                new_set.insert(right);
+                succeeding_pages.insert(left, new_set);
+            }
+
+            if let Some(set) = preceeding_pages.get_mut(&right) {
+                set.insert(left);
+            } else {
+                let mut new_set = HashSet::new();
+                new_set.insert(left);
+                preceeding_pages.insert(right, new_set);
+            }
+        }
+
+        PageOrder {
+            succeeding_pages,
+            preceeding_pages
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_order() {
+        let page_order = PageOrderBuilder::new()
+            .add_rule(47, 53)
+            .add_rule(97, 13)
+            .add_rule(97, 61)
+            .add_rule(97, 47)
+            .add_rule(75, 29)
+            .add_rule(61, 13)
+            .add_rule(75, 53)
+            .add_rule(29, 13)
+            .add_rule(97, 29)
+            .add_rule(53, 29)
+            .add_rule(61, 53)
+            .add_rule(97, 53)
+            .add_rule(61, 29)
+            .add_rule(47, 13)
+            .add_rule(75, 47)
+            .add_rule(97, 75)
+            .add_rule(47, 61)
+            .add_rule(75, 61)
+            .add_rule(47, 29)
+            .add_rule(75, 13)
+            .add_rule(53, 13)
+            .build();
+        
+        let input = vec![75,47,61,53,29];
+        assert!(page_order.is_in_order(&input));
+        assert!(page_order.get_middle_value(&input) == 61);
+
+        let input = vec![97,61,53,29,13];
+        assert!(page_order.is_in_order(&input));
+        assert!(page_order.get_middle_value(&input) == 53);
+
+        let input = vec![75,29,13];
+        assert!(page_order.is_in_order(&input));
+        assert!(page_order.get_middle_value(&input) == 29);
+
+        let input = vec![75,97,47,61,53];
+        assert!(!page_order.is_in_order(&input));
+
+        let input = vec![61,13,29];
+        assert!(!page_order.is_in_order(&input));
+
+        let input = vec![97,13,75,29,47];
+        assert!(!page_order.is_in_order(&input));
+    }
+
+    #[test]
+    fn test_read_sequences_strict() {
+        let input = "47|53\n97|1x\n\n75,47,61\n";
+
+        let err = read_sequences(input, ParseMode::Strict).unwrap_err();
+        assert_eq!(err.location().map(|location| location.line), Some(2));
+
+        assert_eq!(read_sequences(input, ParseMode::Lenient).unwrap(), vec![vec![75, 47, 61]]);
+    }
+
+    #[test]
+    fn test_lenient_skips_garbage_lines() {
+        let queue = Day05::parse("47|53\n47|61\n61|53\n\nfoo\n47,61,53\n", ParseMode::Lenient).unwrap();
+
+        assert_eq!(queue.sequences, vec![vec![47, 61, 53]]);
+        assert_eq!(Day05::part1(&queue).unwrap(), 61);
+    }
+
+    #[test]
+    fn test_page_order_put_in_order() {
+        let page_order = PageOrderBuilder::new()
+            .add_rule(13, 14)
+            .add_rule(14, 15)
+            .build();
+
+        let expected = vec![13, 14, 15];
+
+        assert_eq!(page_order.put_in_order(&[15, 14, 13]), expected);
+        assert_eq!(page_order.put_in_order(&[15, 13, 14]), expected);
+        assert_eq!(page_order.put_in_order(&[14, 15, 13]), expected);
+        assert_eq!(page_order.put_in_order(&[14, 13, 15]), expected);
+        assert_eq!(page_order.put_in_order(&[13, 14, 15]), expected);
+        assert_eq!(page_order.put_in_order(&[13, 15, 14]), expected);
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day05::Day05;
+
+fn main() -> ExitCode {
+    common::cli::run::<Day05>("./puzzles/day05/input.txt")
+}
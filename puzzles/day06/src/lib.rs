@@ -0,0 +1,188 @@
+use common::{Example, Error, ParseMode, Result, Solution};
+use grid::{Grid, Point, Vector};
+
+/// Day 6, only the first part has been solved so far
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const PARTS: u8 = 1;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: include_str!("../example.txt"),
+            answer: "41",
+        },
+    ];
+
+    type Input = PatrolPathReader;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str, mode: ParseMode) -> Result<Self::Input> {
+        PatrolPathReader::parse(input, mode)
+    }
+
+    /// Number of distinct positions the guard visits before leaving the map
+    fn part1(reader: &Self::Input) -> Result<Self::Answer1> {
+        let mut reader = reader.clone();
+        reader.patrol_path();
+
+        Ok(reader.get_distinct_positions())
+    }
+}
+
+common::example_tests!(Day06);
+
+#[derive(Clone, Copy)]
+enum GuardDirection {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl GuardDirection {
+    fn velocity(self) -> Vector {
+        match self {
+            GuardDirection::Up => Vector::UP,
+            GuardDirection::Right => Vector::RIGHT,
+            GuardDirection::Down => Vector::DOWN,
+            GuardDirection::Left => Vector::LEFT,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Open,
+    Obstacle,
+}
+
+#[derive(Clone)]
+pub struct PatrolPathReader {
+    map: Grid<Tile>,
+    traversed_positions: Grid<bool>,
+    guard_direction: GuardDirection,
+    guard_position: Point,
+}
+
+impl PatrolPathReader {
+    pub fn parse(input: &str, mode: ParseMode) -> Result<Self> {
+        let map = Grid::try_parse(input, "`.`, `#` or `^`", |char| match char {
+            '.' | '#' | '^' => Some(char),
+            _ if mode == ParseMode::Lenient => Some('.'),
+            _ => None,
+        })?;
+
+        let guard_position = map
+            .position(|&char| char == '^')
+            .ok_or_else(|| Error::validation("Guard position not found"))?;
+
+        Ok(PatrolPathReader::new(
+            map.map(|&char| if char == '#' { Tile::Obstacle } else { Tile::Open }),
+            guard_position,
+        ))
+    }
+
+    pub fn new(map: Grid<Tile>, guard_position: Point) -> Self {
+        Self {
+            traversed_positions: Grid::filled(map.width(), map.height(), false),
+            map,
+            guard_direction: GuardDirection::Up,
+            guard_position,
+        }
+    }
+
+    fn get_next_guard_position(&self) -> Option<Point> {
+        self.map.offset(self.guard_position, self.guard_direction.velocity())
+    }
+
+    fn get_next_guard_direction(&self) -> GuardDirection {
+        match self.guard_direction {
+            GuardDirection::Up => GuardDirection::Right,
+            GuardDirection::Right => GuardDirection::Down,
+            GuardDirection::Down => GuardDirection::Left,
+            GuardDirection::Left => GuardDirection::Up,
+        }
+    }
+
+    /// Walk the guard until the next step would leave the map
+    pub fn patrol_path(&mut self) {
+        self.traversed_positions[self.guard_position] = true;
+
+        while let Some(next_position) = self.get_next_guard_position() {
+            if self.map[next_position] == Tile::Obstacle {
+                self.guard_direction = self.get_next_guard_direction();
+                continue;
+            }
+
+            self.traversed_positions[next_position] = true;
+            self.guard_position = next_position;
+        }
+    }
+
+    pub fn get_distinct_positions(&self) -> usize {
+        self.traversed_positions.iter().filter(|&(_, &traversed)| traversed).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_strict() {
+        let input = "..#\n.^x\n";
+
+        let err = PatrolPathReader::parse(input, ParseMode::Strict).err().unwrap();
+        assert_eq!(err.to_string(), "Expected `.`, `#` or `^` but found `x` at <input>:2:3");
+
+        assert!(PatrolPathReader::parse(input, ParseMode::Lenient).is_ok());
+    }
+
+    #[test]
+    fn test_parse_trailing_blank_line() {
+        let input = format!("{}\n", include_str!("../example.txt"));
+
+        for mode in [ParseMode::Strict, ParseMode::Lenient] {
+            let reader = Day06::parse(&input, mode).unwrap();
+            assert_eq!(Day06::part1(&reader).unwrap(), 41, "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn test_step_off_the_map_is_not_counted() {
+        // The guard visits the two cells of the column and then leaves, which used to count as a
+        // third position
+        let reader = PatrolPathReader::parse(".\n^\n", ParseMode::Strict).unwrap();
+
+        assert_eq!(Day06::part1(&reader).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_map() {
+        let map = Grid::parse(
+            "....#.....\n\
+             .........#\n\
+             ..........\n\
+             ..#.......\n\
+             .......#..\n\
+             ..........\n\
+             .#..^.....\n\
+             ........#.\n\
+             #.........\n\
+             ......#...",
+            |char| if char == '#' { Tile::Obstacle } else { Tile::Open },
+        )
+        .unwrap();
+
+        let guard_position = Point::new(4, 6);
+
+        let mut patrol_path_reader = PatrolPathReader::new(map, guard_position);
+
+        patrol_path_reader.patrol_path();
+
+        assert_eq!(patrol_path_reader.get_distinct_positions(), 41)
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day06::Day06;
+
+fn main() -> ExitCode {
+    common::cli::run::<Day06>("./puzzles/day06/input.txt")
+}